@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use discard_while::{discard_bytes_while, discard_while, ByteSet};
+
+const LEN: usize = 100_000;
+
+/// The plain `for` loop `discard_while` used before switching to `try_fold`,
+/// kept as a baseline.
+fn discard_while_for_loop<T>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(&T) -> bool,
+) -> (Option<T>, usize) {
+    let mut i = 0;
+    for next in iter {
+        if !cond(&next) {
+            return (Some(next), i);
+        }
+        i += 1;
+    }
+    (None, i)
+}
+
+fn bench_iter<I: IntoIterator<Item = u32>>(c: &mut Criterion, name: &str, make: impl Fn() -> I) {
+    // The sentinel is opaque to the optimizer, so neither loop can be folded away,
+    // but unlike `black_box(n)` it does not force every item through the stack.
+    let stop = black_box(u32::MAX);
+    let mut group = c.benchmark_group(name);
+    group.bench_function("try_fold", |b| {
+        b.iter(|| discard_while(make(), |&n| n != stop))
+    });
+    group.bench_function("for_loop", |b| {
+        b.iter(|| discard_while_for_loop(make(), |&n| n != stop))
+    });
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    let vec: Vec<u32> = (0..LEN as u32).collect();
+    let (front, back) = vec.split_at(LEN / 2);
+    let nested: Vec<Vec<u32>> = vec.chunks(64).map(<[u32]>::to_vec).collect();
+    let deque: VecDeque<u32> = {
+        // Rotate so that the contents wrap around the end of the ring buffer.
+        let mut deque: VecDeque<u32> = vec.iter().copied().collect();
+        deque.rotate_left(LEN / 3);
+        deque
+    };
+
+    bench_iter(c, "slice", || vec.iter().copied());
+    bench_iter(c, "range", || 0..LEN as u32);
+    bench_iter(c, "chain", || front.iter().chain(back).copied());
+    bench_iter(c, "flat_map", || {
+        nested.iter().flat_map(|v| v.iter().copied())
+    });
+    bench_iter(c, "vec_deque", || deque.iter().copied());
+}
+
+fn bench_bytes(c: &mut Criterion, name: &str, set: ByteSet, cond: impl Fn(&u8) -> bool) {
+    let mut input: Vec<u8> = (0..=u8::MAX)
+        .filter(|&b| set.contains(b))
+        .cycle()
+        .take(LEN)
+        .collect();
+    input.push(b'~');
+    let mut group = c.benchmark_group(name);
+    group.bench_function("discard_bytes_while", |b| {
+        b.iter(|| discard_bytes_while(black_box(&input), set))
+    });
+    group.bench_function("discard_while", |b| {
+        b.iter(|| discard_while(black_box(&input), |&&b| cond(&b)))
+    });
+    group.finish();
+}
+
+fn byte_benches(c: &mut Criterion) {
+    bench_bytes(
+        c,
+        "bytes",
+        ByteSet::ASCII_WHITESPACE,
+        u8::is_ascii_whitespace,
+    );
+    bench_bytes(c, "bytes_range", ByteSet::ASCII_DIGIT, u8::is_ascii_digit);
+    let alphanumeric = ByteSet::range(b'0', b'9')
+        .union(ByteSet::range(b'A', b'Z'))
+        .union(ByteSet::range(b'a', b'z'));
+    bench_bytes(c, "bytes_union", alphanumeric, u8::is_ascii_alphanumeric);
+    let scattered = ByteSet::new(b"!#%&*+-/<=>?@^|");
+    bench_bytes(c, "bytes_table", scattered, |b| {
+        b"!#%&*+-/<=>?@^|".contains(b)
+    });
+}
+
+criterion_group!(discard_while_benches, benches, byte_benches);
+criterion_main!(discard_while_benches);
@@ -0,0 +1,166 @@
+//! Support for Tokio's [`AsyncBufRead`] readers, enabled by the `tokio` feature.
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use std::io::{self, ErrorKind};
+
+use tokio::io::AsyncBufRead;
+
+/// Convenience trait to allow discarding bytes from Tokio's [`AsyncBufRead`] readers.
+/// This trait is implemented for every [`AsyncBufRead`] that is [`Unpin`].
+///
+/// This is the asynchronous counterpart of [`BufReadDiscardWhile`](crate::BufReadDiscardWhile).
+///
+/// # Cancellation Safety
+///
+/// The returned futures are cancellation safe.
+/// Each byte is consumed from the reader as soon as it is discarded,
+/// and the first byte that no longer satisfies the condition is only consumed
+/// when the future completes.
+/// If a future is dropped before completing, the bytes discarded so far stay consumed,
+/// but no other byte is lost, so discarding can be resumed by calling the method again.
+///
+/// The number of bytes discarded so far is only kept in the future,
+/// and can be read with [`DiscardBytesWhile::count`] before it is dropped.
+/// A future created by calling the method again counts from zero.
+pub trait AsyncBufReadDiscardWhile: AsyncBufRead + Unpin {
+    /// Advance the reader as long as a condition on the read bytes holds.
+    /// Returns a future resolving to the first byte that no longer satisfies the condition,
+    /// if any, and the number of bytes discarded.
+    ///
+    /// The returned byte is consumed as well.
+    /// To leave it in the reader, use [`discard_while_peeking`](Self::discard_while_peeking).
+    ///
+    /// # Errors
+    ///
+    /// Errors of kind [`ErrorKind::Interrupted`] are retried.
+    /// Any other error is returned, with the bytes discarded before it staying consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::AsyncBufReadDiscardWhile;
+    /// # futures::executor::block_on(async {
+    /// let mut reader = &b"\r\n\r\nHTTP/1.1"[..];
+    /// let result = reader.discard_while(|b| b.is_ascii_whitespace()).await?;
+    /// assert_eq!(result, (Some(b'H'), 4));
+    /// assert_eq!(reader, b"TTP/1.1");
+    /// # Ok::<(), std::io::Error>(())
+    /// # }).unwrap();
+    /// ```
+    fn discard_while<F>(&mut self, cond: F) -> DiscardBytesWhile<'_, Self, F>
+    where
+        F: FnMut(&u8) -> bool,
+    {
+        DiscardBytesWhile::new(self, cond, true)
+    }
+
+    /// Advance the reader as long as a condition on the read bytes holds,
+    /// leaving the first byte that no longer satisfies the condition in the reader.
+    /// Returns a future resolving to that byte, if any, and the number of bytes discarded.
+    ///
+    /// # Errors
+    ///
+    /// Errors of kind [`ErrorKind::Interrupted`] are retried.
+    /// Any other error is returned, with the bytes discarded before it staying consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::AsyncBufReadDiscardWhile;
+    /// # futures::executor::block_on(async {
+    /// let mut reader = &b"   hello"[..];
+    /// let result = reader.discard_while_peeking(|&b| b == b' ').await?;
+    /// assert_eq!(result, (Some(b'h'), 3));
+    /// assert_eq!(reader, b"hello");
+    /// # Ok::<(), std::io::Error>(())
+    /// # }).unwrap();
+    /// ```
+    fn discard_while_peeking<F>(&mut self, cond: F) -> DiscardBytesWhile<'_, Self, F>
+    where
+        F: FnMut(&u8) -> bool,
+    {
+        DiscardBytesWhile::new(self, cond, false)
+    }
+}
+
+impl<R: AsyncBufRead + Unpin + ?Sized> AsyncBufReadDiscardWhile for R {}
+
+/// Future for the [`discard_while`](AsyncBufReadDiscardWhile::discard_while)
+/// and [`discard_while_peeking`](AsyncBufReadDiscardWhile::discard_while_peeking) methods.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DiscardBytesWhile<'a, R: ?Sized, F> {
+    reader: &'a mut R,
+    cond: F,
+    count: u64,
+    consume_stopper: bool,
+}
+
+impl<'a, R: ?Sized, F> DiscardBytesWhile<'a, R, F> {
+    fn new(reader: &'a mut R, cond: F, consume_stopper: bool) -> Self {
+        DiscardBytesWhile {
+            reader,
+            cond,
+            count: 0,
+            consume_stopper,
+        }
+    }
+
+    /// Returns the number of bytes discarded so far.
+    ///
+    /// The discarded bytes are consumed from the reader even if the future is dropped
+    /// before completing, so this is how many bytes were lost to a cancelled future.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl<R: fmt::Debug + ?Sized, F> fmt::Debug for DiscardBytesWhile<'_, R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiscardBytesWhile")
+            .field("reader", &self.reader)
+            .field("count", &self.count)
+            .field("consume_stopper", &self.consume_stopper)
+            .finish()
+    }
+}
+
+impl<R: ?Sized, F> Unpin for DiscardBytesWhile<'_, R, F> {}
+
+impl<R, F> Future for DiscardBytesWhile<'_, R, F>
+where
+    R: AsyncBufRead + Unpin + ?Sized,
+    F: FnMut(&u8) -> bool,
+{
+    type Output = io::Result<(Option<u8>, u64)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            let buf = match ready!(Pin::new(&mut *this.reader).poll_fill_buf(cx)) {
+                Ok(buf) => buf,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Poll::Ready(Err(err)),
+            };
+            if buf.is_empty() {
+                return Poll::Ready(Ok((None, this.count)));
+            }
+            match buf.iter().position(|b| !(this.cond)(b)) {
+                Some(i) => {
+                    let stopper = buf[i];
+                    let amount = i + usize::from(this.consume_stopper);
+                    Pin::new(&mut *this.reader).consume(amount);
+                    this.count += i as u64;
+                    return Poll::Ready(Ok((Some(stopper), this.count)));
+                }
+                None => {
+                    let len = buf.len();
+                    Pin::new(&mut *this.reader).consume(len);
+                    this.count += len as u64;
+                }
+            }
+        }
+    }
+}
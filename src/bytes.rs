@@ -0,0 +1,366 @@
+//! Fast discarding of bytes from byte slices.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::size_of;
+
+/// The maximum number of members for which [`ByteSet`] compares whole words
+/// against each member instead of looking up every byte in its table.
+const MAX_SPLAT_MEMBERS: usize = 5;
+/// The maximum number of ranges of consecutive members for which [`ByteSet`]
+/// compares whole words against each range instead of looking up every byte in its table.
+const MAX_RANGES: usize = 4;
+
+/// The number of bytes processed at once.
+const WORD: usize = size_of::<usize>();
+/// The number of words checked before branching.
+const UNROLL: usize = 4;
+/// A word with every byte set to `0x01`.
+const LO: usize = usize::MAX / 0xFF;
+/// A word with every byte set to `0x80`.
+const HI: usize = LO << 7;
+
+/// Returns a word with the high bit of each byte set iff that byte of `x` is nonzero.
+///
+/// Unlike the usual "has zero byte" trick, this is exact for every byte,
+/// because adding `0x7F` to a byte below `0x80` never carries into the next byte.
+const fn nonzero_bytes(x: usize) -> usize {
+    (((x & !HI) + !HI) | x) & HI
+}
+
+/// A set of bytes, used by [`discard_bytes_while`].
+///
+/// Sets with few members, like the ones for whitespace or zero fill,
+/// and sets made of at most four ranges of consecutive bytes,
+/// like the ones for digits or alphanumeric characters, are scanned a word at a time.
+/// Other sets are scanned one byte at a time with a lookup table.
+///
+/// # Examples
+///
+/// ```
+/// # use discard_while::ByteSet;
+/// let set = ByteSet::new(b" \t");
+/// assert!(set.contains(b'\t'));
+/// assert!(!set.contains(b'\n'));
+/// assert!(ByteSet::ASCII_WHITESPACE.contains(b'\n'));
+/// ```
+///
+/// Sets are equal if they contain the same bytes, regardless of insertion order.
+///
+/// ```
+/// # use discard_while::ByteSet;
+/// assert_eq!(ByteSet::new(b"ab"), ByteSet::new(b"ba"));
+/// assert_eq!(ByteSet::new(b"abcdefg"), ByteSet::new(b"gfedcba"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ByteSet {
+    table: [u64; 4],
+    len: u16,
+    members: [u8; MAX_SPLAT_MEMBERS],
+}
+
+impl ByteSet {
+    /// The empty set.
+    pub const EMPTY: ByteSet = ByteSet {
+        table: [0; 4],
+        len: 0,
+        members: [0; MAX_SPLAT_MEMBERS],
+    };
+
+    /// ASCII whitespace, as defined by [`u8::is_ascii_whitespace`].
+    pub const ASCII_WHITESPACE: ByteSet = ByteSet::new(b"\t\n\x0C\r ");
+
+    /// ASCII decimal digits, as defined by [`u8::is_ascii_digit`].
+    pub const ASCII_DIGIT: ByteSet = ByteSet::range(b'0', b'9');
+
+    /// Creates a set containing the given bytes.
+    pub const fn new(bytes: &[u8]) -> ByteSet {
+        let mut set = ByteSet::EMPTY;
+        let mut i = 0;
+        while i < bytes.len() {
+            set = set.with(bytes[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Creates a set containing all bytes from `start` to `end`, inclusive.
+    pub const fn range(start: u8, end: u8) -> ByteSet {
+        let mut set = ByteSet::EMPTY;
+        let mut byte = start as u16;
+        while byte <= end as u16 {
+            set = set.with(byte as u8);
+            byte += 1;
+        }
+        set
+    }
+
+    /// Returns this set with `byte` added.
+    pub const fn with(mut self, byte: u8) -> ByteSet {
+        if self.contains(byte) {
+            return self;
+        }
+        self.table[(byte >> 6) as usize] |= 1 << (byte & 63);
+        if (self.len as usize) < MAX_SPLAT_MEMBERS {
+            self.members[self.len as usize] = byte;
+        }
+        self.len += 1;
+        self
+    }
+
+    /// Returns the union of this set and `other`.
+    pub const fn union(self, other: ByteSet) -> ByteSet {
+        let mut set = self;
+        let mut byte = 0;
+        while byte <= u8::MAX as u16 {
+            if other.contains(byte as u8) {
+                set = set.with(byte as u8);
+            }
+            byte += 1;
+        }
+        set
+    }
+
+    /// Returns `true` if the set contains `byte`.
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.table[(byte >> 6) as usize] & (1 << (byte & 63)) != 0
+    }
+
+    /// Returns the number of bytes in the set.
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns `true` if the set contains no bytes.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the length of the longest prefix of `bytes` whose bytes are all in the set.
+    fn prefix_len(&self, bytes: &[u8]) -> usize {
+        let offset = match self.len() {
+            0 => 0,
+            1 => splat_prefix_len(bytes, [self.members[0]]),
+            2 => splat_prefix_len(bytes, [self.members[0], self.members[1]]),
+            3 => splat_prefix_len(bytes, [self.members[0], self.members[1], self.members[2]]),
+            4 => splat_prefix_len(
+                bytes,
+                [
+                    self.members[0],
+                    self.members[1],
+                    self.members[2],
+                    self.members[3],
+                ],
+            ),
+            5 => splat_prefix_len(bytes, self.members),
+            _ => match self.ranges() {
+                Some((ranges, 1)) => range_prefix_len(bytes, [ranges[0]]),
+                Some((ranges, 2)) => range_prefix_len(bytes, [ranges[0], ranges[1]]),
+                Some((ranges, 3)) => range_prefix_len(bytes, [ranges[0], ranges[1], ranges[2]]),
+                Some((ranges, _)) => range_prefix_len(bytes, ranges),
+                None => 0,
+            },
+        };
+        let rest = &bytes[offset..];
+        offset
+            + rest
+                .iter()
+                .position(|&b| !self.contains(b))
+                .unwrap_or(rest.len())
+    }
+
+    /// Splits the set into ranges of consecutive members,
+    /// returning them and their number, or [`None`] if there are more than [`MAX_RANGES`].
+    fn ranges(&self) -> Option<([ByteRange; MAX_RANGES], usize)> {
+        let mut ranges = [ByteRange::new(0, 0); MAX_RANGES];
+        let mut count = 0;
+        let mut from = 0;
+        while let Some(start) = self.next_position(from, true) {
+            let end = self.next_position(start, false).unwrap_or(256);
+            *ranges.get_mut(count)? = ByteRange::new(start as u8, (end - start) as u16);
+            count += 1;
+            from = end;
+        }
+        Some((ranges, count))
+    }
+
+    /// Returns the first byte, starting at `from`, whose membership in the set is `member`.
+    fn next_position(&self, from: usize, member: bool) -> Option<usize> {
+        (from / 64..4).find_map(|i| {
+            let bits = if member {
+                self.table[i]
+            } else {
+                !self.table[i]
+            };
+            // Ignore the bytes before `from` in its own word of the table.
+            let bits = if i == from / 64 {
+                bits & (u64::MAX << (from % 64))
+            } else {
+                bits
+            };
+            (bits != 0).then(|| i * 64 + bits.trailing_zeros() as usize)
+        })
+    }
+}
+
+/// A range of consecutive bytes, prepared for comparing every byte of a word against it.
+#[derive(Clone, Copy)]
+struct ByteRange {
+    /// The first byte of the range in every byte.
+    start: usize,
+    /// The amount to add to the low seven bits of a byte's offset from the start
+    /// to carry into its high bit iff the offset is outside the range.
+    bias: usize,
+    /// Whether the range has more than 128 bytes,
+    /// so that every offset below 128 is inside it.
+    wide: bool,
+}
+
+impl ByteRange {
+    /// Prepares the range of `len` bytes starting at `start`, where `len` is at most 256.
+    fn new(start: u8, len: u16) -> ByteRange {
+        let wide = len > 128;
+        let bias = if wide { 256 - len } else { 128 - len };
+        ByteRange {
+            start: LO * start as usize,
+            bias: LO * bias as usize,
+            wide,
+        }
+    }
+
+    /// Returns a word with the high bit of each byte set iff that byte of `x` is in the range.
+    fn contains_bytes(&self, x: usize) -> usize {
+        // Subtract the start from every byte without borrowing from the next byte.
+        let offset = ((x | HI) - (self.start & !HI)) ^ ((x ^ !self.start) & HI);
+        let below = !((offset & !HI) + self.bias) & HI;
+        if self.wide {
+            below | (!offset & HI)
+        } else {
+            below & !offset
+        }
+    }
+}
+
+/// Returns the length of a prefix of `bytes` whose bytes are all in `members`,
+/// which is at most a few bytes short of the longest such prefix.
+///
+/// The number of members is a const generic so that the comparisons are fully unrolled.
+fn splat_prefix_len<const N: usize>(bytes: &[u8], members: [u8; N]) -> usize {
+    let splats = members.map(|member| LO * member as usize);
+    word_prefix_len(bytes, |word| {
+        splats
+            .iter()
+            .fold(HI, |outside, splat| outside & nonzero_bytes(word ^ splat))
+    })
+}
+
+/// Returns the length of a prefix of `bytes` whose bytes are all in `ranges`,
+/// which is at most a few bytes short of the longest such prefix.
+///
+/// The number of ranges is a const generic so that the comparisons are fully unrolled.
+fn range_prefix_len<const N: usize>(bytes: &[u8], ranges: [ByteRange; N]) -> usize {
+    word_prefix_len(bytes, |word| {
+        !ranges
+            .iter()
+            .fold(0, |inside, range| inside | range.contains_bytes(word))
+            & HI
+    })
+}
+
+/// Returns the length of a prefix of `bytes` made of whole words
+/// in which `outside` finds no bytes, plus the bytes before the first one it finds.
+///
+/// `outside` must return a word with the high bit of each byte set
+/// iff that byte of its argument is not in the set.
+fn word_prefix_len(bytes: &[u8], outside: impl Fn(usize) -> usize) -> usize {
+    let outside = |chunk: &[u8]| outside(usize::from_le_bytes(chunk.try_into().unwrap()));
+    let mut offset = 0;
+    // Skip blocks of several words with a single branch.
+    for block in bytes.chunks_exact(WORD * UNROLL) {
+        if block
+            .chunks_exact(WORD)
+            .fold(0, |acc, chunk| acc | outside(chunk))
+            != 0
+        {
+            break;
+        }
+        offset += WORD * UNROLL;
+    }
+    for chunk in bytes[offset..].chunks_exact(WORD) {
+        let outside = outside(chunk);
+        if outside != 0 {
+            return offset + outside.trailing_zeros() as usize / 8;
+        }
+        offset += WORD;
+    }
+    offset
+}
+
+// Only the table is compared and hashed,
+// since `members` depends on the order in which bytes were added.
+impl PartialEq for ByteSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.table == other.table
+    }
+}
+
+impl Eq for ByteSet {}
+
+impl Hash for ByteSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.table.hash(state);
+    }
+}
+
+impl Default for ByteSet {
+    fn default() -> Self {
+        ByteSet::EMPTY
+    }
+}
+
+impl fmt::Debug for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries((0..=u8::MAX).filter(|&b| self.contains(b)))
+            .finish()
+    }
+}
+
+impl FromIterator<u8> for ByteSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        iter.into_iter().fold(ByteSet::EMPTY, ByteSet::with)
+    }
+}
+
+/// Advance through a byte slice as long as the bytes are in a [`ByteSet`].
+/// Returns the first byte that is not in the set, if any,
+/// and the number of bytes discarded.
+///
+/// This gives the same result as
+/// [`discard_while(bytes, |b| set.contains(*b))`](crate::discard_while),
+/// but for most sets, instead of checking one byte at a time, it checks whole words at once.
+/// See [`ByteSet`] for which sets are scanned a word at a time.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::{discard_bytes_while, ByteSet};
+/// let input = b"  \t\r\n  hello";
+/// let result = discard_bytes_while(input, ByteSet::ASCII_WHITESPACE);
+/// assert_eq!(result, (Some(b'h'), 7));
+/// ```
+///
+/// If all bytes are in the set, [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::{discard_bytes_while, ByteSet};
+/// let input = [0u8; 100];
+/// let result = discard_bytes_while(&input, ByteSet::new(&[0]));
+/// assert_eq!(result, (None, 100));
+/// ```
+pub fn discard_bytes_while(bytes: &[u8], set: ByteSet) -> (Option<u8>, usize) {
+    let count = set.prefix_len(bytes);
+    (bytes.get(count).copied(), count)
+}
@@ -0,0 +1,100 @@
+//! Support for [`BufRead`] readers, enabled by the `std` feature.
+
+use std::io::{self, BufRead, ErrorKind};
+
+/// Convenience trait to allow discarding bytes from [`BufRead`] readers.
+/// This trait is implemented for every [`BufRead`].
+///
+/// The bytes are checked directly in the reader's buffer
+/// using [`fill_buf`](BufRead::fill_buf) and [`consume`](BufRead::consume),
+/// so no buffering is lost.
+pub trait BufReadDiscardWhile: BufRead {
+    /// Advance the reader as long as a condition on the read bytes holds.
+    /// Returns the first byte that no longer satisfies the condition, if any,
+    /// and the number of bytes discarded.
+    ///
+    /// The returned byte is consumed as well, like with [`discard_while`](crate::discard_while).
+    /// To leave it in the reader, use [`discard_while_peeking`](Self::discard_while_peeking).
+    ///
+    /// # Errors
+    ///
+    /// Errors of kind [`ErrorKind::Interrupted`] are retried.
+    /// Any other error is returned, with the bytes discarded before it staying consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::BufReadDiscardWhile;
+    /// # use std::io::{BufRead, BufReader};
+    /// let mut reader = BufReader::with_capacity(4, &b"\0\0\0\0\0\0hello"[..]);
+    /// let result = reader.discard_while(|&b| b == 0)?;
+    /// assert_eq!(result, (Some(b'h'), 6));
+    /// let mut rest = String::new();
+    /// reader.read_line(&mut rest)?;
+    /// assert_eq!(rest, "ello");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn discard_while(&mut self, cond: impl FnMut(&u8) -> bool) -> io::Result<(Option<u8>, u64)> {
+        discard_bytes_while(self, cond, true)
+    }
+
+    /// Advance the reader as long as a condition on the read bytes holds,
+    /// leaving the first byte that no longer satisfies the condition in the reader.
+    /// Returns that byte, if any, and the number of bytes discarded.
+    ///
+    /// # Errors
+    ///
+    /// Errors of kind [`ErrorKind::Interrupted`] are retried.
+    /// Any other error is returned, with the bytes discarded before it staying consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::BufReadDiscardWhile;
+    /// # use std::io::BufRead;
+    /// let mut reader = &b"   hello"[..];
+    /// let result = reader.discard_while_peeking(|b| b.is_ascii_whitespace())?;
+    /// assert_eq!(result, (Some(b'h'), 3));
+    /// assert_eq!(reader, b"hello");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn discard_while_peeking(
+        &mut self,
+        cond: impl FnMut(&u8) -> bool,
+    ) -> io::Result<(Option<u8>, u64)> {
+        discard_bytes_while(self, cond, false)
+    }
+}
+
+impl<R: BufRead + ?Sized> BufReadDiscardWhile for R {}
+
+/// The implementation of the [`BufReadDiscardWhile`] methods.
+fn discard_bytes_while<R: BufRead + ?Sized>(
+    reader: &mut R,
+    mut cond: impl FnMut(&u8) -> bool,
+    consume_stopper: bool,
+) -> io::Result<(Option<u8>, u64)> {
+    let mut count = 0;
+    loop {
+        let buf = match reader.fill_buf() {
+            Ok(buf) => buf,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        if buf.is_empty() {
+            return Ok((None, count));
+        }
+        match buf.iter().position(|b| !cond(b)) {
+            Some(i) => {
+                let stopper = buf[i];
+                reader.consume(i + usize::from(consume_stopper));
+                return Ok((Some(stopper), count + i as u64));
+            }
+            None => {
+                let len = buf.len();
+                reader.consume(len);
+                count += len as u64;
+            }
+        }
+    }
+}
@@ -4,6 +4,63 @@
 //!
 //! To use, either `use discard_while::discard_while` to get the function,
 //! or `use discard_while::DiscardWhile` to get the convenience trait.
+//! For [`Peekable`] iterators, the `discard_while_peeking` method of [`DiscardWhile`]
+//! discards items without consuming the first non-matching one.
+//! For byte slices, [`discard_bytes_while`] scans for bytes outside a [`ByteSet`],
+//! a word at a time for small sets and sets made of a few ranges.
+//!
+//! # Features
+//!
+//! - `std`: Adds `BufReadDiscardWhile` for buffered readers, see the `io` module,
+//!   and `par_discard_while_slice` for scanning slices on multiple threads,
+//!   see the `thread` module. Implements `std::error::Error` for the error types.
+//!   Implies `alloc`.
+//! - `alloc`: Implements [`Sink`] for `Vec`.
+//! - `heapless`: Implements [`Sink`] for `heapless::Vec`.
+//! - `futures`: Adds `StreamDiscardWhile` for asynchronous streams, see the `stream` module.
+//! - `rayon`: Adds `ParallelDiscardWhile` for Rayon's indexed parallel iterators,
+//!   see the `par` module.
+//! - `tokio`: Adds `AsyncBufReadDiscardWhile` for Tokio's asynchronous buffered readers,
+//!   see the `async_io` module. Implies `std`.
+
+use core::fmt;
+use core::iter::Peekable;
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod bytes;
+pub use bytes::{discard_bytes_while, ByteSet};
+mod sink;
+pub use sink::{discard_while_into, DiscardedInto, Extending, Sink, SinkFull, SliceSink};
+mod skip;
+pub use skip::SkipWhileCounted;
+
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "std")]
+pub use io::BufReadDiscardWhile;
+#[cfg(feature = "std")]
+pub mod thread;
+#[cfg(feature = "std")]
+pub use thread::par_discard_while_slice;
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(feature = "futures")]
+pub use stream::StreamDiscardWhile;
+#[cfg(feature = "rayon")]
+pub mod par;
+#[cfg(feature = "rayon")]
+pub use par::{par_discard_while, ParallelDiscardWhile};
+#[cfg(feature = "tokio")]
+pub mod async_io;
+#[cfg(feature = "tokio")]
+pub use async_io::AsyncBufReadDiscardWhile;
 
 /// Advance an iterator as long as a condition on the yielded items holds.
 /// Returns the first item that no longer satisfies the condition, if any,
@@ -61,81 +118,2162 @@
 /// [`position`]: Iterator::position
 pub fn discard_while<T>(
     iter: impl IntoIterator<Item = T>,
-    mut cond: impl FnMut(&T) -> bool,
+    cond: impl FnMut(&T) -> bool,
 ) -> (Option<T>, usize) {
-    let iter = iter.into_iter();
-    let mut i = 0;
-    for next in iter {
+    discard_while_counting(iter, cond, 0, |i| i + 1)
+}
+
+/// The loop shared by [`discard_while`] and its counting variants.
+///
+/// This uses [`try_fold`](Iterator::try_fold) rather than a `for` loop,
+/// so that iterators with a specialized internal iteration, like [`Chain`](core::iter::Chain),
+/// are advanced efficiently.
+fn discard_while_counting<T, C>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(&T) -> bool,
+    zero: C,
+    mut increment: impl FnMut(C) -> C,
+) -> (Option<T>, C) {
+    let flow = iter.into_iter().try_fold(zero, |i, next| {
+        if cond(&next) {
+            ControlFlow::Continue(increment(i))
+        } else {
+            ControlFlow::Break((next, i))
+        }
+    });
+    match flow {
+        ControlFlow::Continue(i) => (None, i),
+        ControlFlow::Break((next, i)) => (Some(next), i),
+    }
+}
+
+/// The result of [`discard_while_owned`].
+///
+/// Holds the first non-matching element, if any, the amount of discarded elements,
+/// and the iterator with all elements that were not yet consumed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscardedOwned<I: Iterator> {
+    /// The first element that did not satisfy the condition, if any.
+    pub stopper: Option<I::Item>,
+    /// The number of elements that were discarded.
+    pub count: usize,
+    /// The remaining elements after the stopper.
+    pub rest: I,
+}
+
+/// Advance an owned iterator as long as a condition on the yielded items holds.
+/// Returns the first item that no longer satisfies the condition, if any,
+/// the number of items discarded, and the remaining iterator.
+///
+/// This works like [`discard_while`], but instead of dropping the iterator
+/// it hands it back in the result, so there is no need to pass `&mut iter`.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_owned;
+/// let v = vec![1, 2, 3, 4, 5, 6];
+/// let result = discard_while_owned(v, |&n| n < 3);
+/// assert_eq!(result.stopper, Some(3));
+/// assert_eq!(result.count, 2);
+/// assert_eq!(result.rest.collect::<Vec<_>>(), [4, 5, 6]);
+/// ```
+///
+/// If the iterator ends before an item that does not fulfill the condition
+/// is encountered, the remaining iterator is empty.
+///
+/// ```
+/// # use discard_while::discard_while_owned;
+/// let result = discard_while_owned(vec![1, 2, 3], |_| true);
+/// assert_eq!(result.stopper, None);
+/// assert_eq!(result.count, 3);
+/// assert_eq!(result.rest.len(), 0);
+/// ```
+pub fn discard_while_owned<I: IntoIterator>(
+    iter: I,
+    cond: impl FnMut(&I::Item) -> bool,
+) -> DiscardedOwned<I::IntoIter> {
+    let mut iter = iter.into_iter();
+    let (stopper, count) = discard_while(&mut iter, cond);
+    DiscardedOwned {
+        stopper,
+        count,
+        rest: iter,
+    }
+}
+
+/// The error returned by [`try_discard_while`] when the condition fails.
+///
+/// Holds the error returned by the condition, the item it was called on,
+/// and the amount of elements discarded before that item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateError<T, E> {
+    /// The error returned by the condition.
+    pub error: E,
+    /// The item the condition failed on.
+    pub item: T,
+    /// The number of elements that were discarded before the failure.
+    pub count: usize,
+}
+
+impl<T, E: fmt::Display> fmt::Display for PredicateError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "condition failed after discarding {} elements: {}",
+            self.count, self.error
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, E: Error + 'static> Error for PredicateError<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The result of [`try_discard_while`].
+pub type TryDiscarded<T, E> = Result<(Option<T>, usize), PredicateError<T, E>>;
+
+/// Advance an iterator as long as a fallible condition on the yielded items holds.
+/// Returns the first item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`], but stops as soon as the condition returns an error.
+/// In that case, the error is returned together with the item it was called on
+/// and the number of items discarded so far, see [`PredicateError`].
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::try_discard_while;
+/// let mut iter = ["1", "2", "30", "4"].into_iter();
+/// let result = try_discard_while(&mut iter, |s| s.parse::<u8>().map(|n| n < 10));
+/// assert_eq!(result, Ok((Some("30"), 2)));
+/// assert_eq!(iter.next(), Some("4"));
+/// ```
+///
+/// If the condition returns an error, the error is returned
+/// along with the offending item and the number of discarded items.
+///
+/// ```
+/// # use discard_while::try_discard_while;
+/// let mut iter = ["1", "2", "x", "4"].into_iter();
+/// let err = try_discard_while(&mut iter, |s| s.parse::<u8>().map(|n| n < 10)).unwrap_err();
+/// assert_eq!(err.item, "x");
+/// assert_eq!(err.count, 2);
+/// assert_eq!(iter.next(), Some("4"));
+/// ```
+pub fn try_discard_while<T, E>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(&T) -> Result<bool, E>,
+) -> TryDiscarded<T, E> {
+    let flow = iter.into_iter().try_fold(0, |i, next| match cond(&next) {
+        Ok(true) => ControlFlow::Continue(i + 1),
+        Ok(false) => ControlFlow::Break(Ok((Some(next), i))),
+        Err(error) => ControlFlow::Break(Err(PredicateError {
+            error,
+            item: next,
+            count: i,
+        })),
+    });
+    match flow {
+        ControlFlow::Continue(i) => Ok((None, i)),
+        ControlFlow::Break(result) => result,
+    }
+}
+
+/// The error returned by [`checked_discard_while`] when the discard count would overflow.
+///
+/// Holds the item that would have been discarded as the `usize::MAX + 1`th element
+/// and the number of elements discarded before it, which is always `usize::MAX`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountOverflow<T> {
+    /// The item that would have overflowed the count.
+    pub item: T,
+    /// The number of elements that were discarded before the overflow.
+    pub count: usize,
+}
+
+impl<T> fmt::Display for CountOverflow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "discard count overflowed after {} elements", self.count)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> Error for CountOverflow<T> {}
+
+/// The result of [`checked_discard_while`].
+pub type CheckedDiscarded<T> = Result<(Option<T>, usize), CountOverflow<T>>;
+
+/// Advance an iterator as long as a condition on the yielded items holds,
+/// stopping instead of overflowing the discard count.
+/// Returns the first item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`], but if `usize::MAX` items have been discarded
+/// and the next item still satisfies the condition, it stops and returns
+/// that item in a [`CountOverflow`] error instead of overflowing.
+/// The iterator can then be resumed after that item.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::checked_discard_while;
+/// let mut range = 1..=10;
+/// let result = checked_discard_while(&mut range, |&n| n != 5);
+/// assert_eq!(result, Ok((Some(5), 4)));
+/// assert_eq!(range, 6..=10);
+/// ```
+pub fn checked_discard_while<T>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(&T) -> bool,
+) -> CheckedDiscarded<T> {
+    let flow = iter.into_iter().try_fold(0usize, |i, next| {
         if !cond(&next) {
-            return (Some(next), i);
+            return ControlFlow::Break(Ok((Some(next), i)));
         }
-        i += 1;
+        match i.checked_add(1) {
+            Some(i) => ControlFlow::Continue(i),
+            None => ControlFlow::Break(Err(CountOverflow {
+                item: next,
+                count: i,
+            })),
+        }
+    });
+    match flow {
+        ControlFlow::Continue(i) => Ok((None, i)),
+        ControlFlow::Break(result) => result,
     }
-    (None, i)
 }
 
-/// Convenience trait to allow using [`discard_while`] as a method.
-/// This trait is implemented for every [`Iterator`].
-pub trait DiscardWhile: Iterator {
-    /// Advance the iterator as long as a condition on the yielded items holds.
-    /// Returns the first item that no longer satisfies the condition, if any,
-    /// and the number of items discarded.
-    ///
-    /// This is similar to a combination of [`find`] and [`position`].
-    ///
-    /// # Overflow Behavior
-    ///
-    /// The method does no guarding against overflows, so if there are more than `usize::MAX`
-    /// non-matching elements, it either produces the wrong result or panics.
-    /// If overflow checks are enabled, a panic is guaranteed.
-    ///
-    /// # Panics
-    ///
-    /// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```
-    /// # use discard_while::DiscardWhile;
-    /// let mut range = 1..=10;
-    /// let result = range.discard_while(|&n| n != 5);
-    /// assert_eq!(result, (Some(5), 4));
-    /// assert_eq!(range, 6..=10);
-    /// ```
-    ///
-    /// If the iterator ends before an item that does not fulfill the condition
-    /// is encountered, [`None`] is returned as the first return value.
-    ///
-    /// ```
-    /// # use discard_while::DiscardWhile;
-    /// let mut range = 1..=10;
-    /// let result = range.discard_while(|&n| true);
-    /// assert_eq!(result, (None, 10));
-    /// assert!(range.is_empty());
-    /// ```
-    /// If the first element that is encountered does not fulfill the condition,
-    /// `0` is returned as the second return value.
-    ///
-    /// ```
-    /// # use discard_while::DiscardWhile;
-    /// let mut range = 1..=10;
-    /// let result = range.discard_while(|&n| false);
-    /// assert_eq!(result, (Some(1), 0));
-    /// assert_eq!(range, 2..=10);
-    /// ```
-    /// [`find`]: Iterator::find
-    /// [`position`]: Iterator::position
-    fn discard_while(
-        &mut self,
-        cond: impl FnMut(&Self::Item) -> bool,
-    ) -> (Option<Self::Item>, usize)
-    where
-        Self: Sized,
-    {
-        discard_while(self, cond)
+/// Advance an iterator as long as a condition on the yielded items holds,
+/// saturating the discard count at `usize::MAX`.
+/// Returns the first item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`], but never overflows.
+/// If more than `usize::MAX` items are discarded, the returned count is `usize::MAX`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::saturating_discard_while;
+/// let mut range = 1..=10;
+/// let result = saturating_discard_while(&mut range, |&n| n != 5);
+/// assert_eq!(result, (Some(5), 4));
+/// assert_eq!(range, 6..=10);
+/// ```
+pub fn saturating_discard_while<T>(
+    iter: impl IntoIterator<Item = T>,
+    cond: impl FnMut(&T) -> bool,
+) -> (Option<T>, usize) {
+    discard_while_counting(iter, cond, 0, |i: usize| i.saturating_add(1))
+}
+
+/// Advance an iterator as long as a condition on the yielded items holds,
+/// counting the discarded items in a [`u64`].
+/// Returns the first item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`], but allows counting beyond `usize::MAX`
+/// on targets where `usize` is smaller than 64 bits.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `u64::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `u64::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_u64;
+/// let mut range = 1..=10;
+/// let result = discard_while_u64(&mut range, |&n| n != 5);
+/// assert_eq!(result, (Some(5), 4u64));
+/// assert_eq!(range, 6..=10);
+/// ```
+pub fn discard_while_u64<T>(
+    iter: impl IntoIterator<Item = T>,
+    cond: impl FnMut(&T) -> bool,
+) -> (Option<T>, u64) {
+    discard_while_counting(iter, cond, 0, |i: u64| i + 1)
+}
+
+/// Advance an iterator as long as a condition on the yielded items holds,
+/// counting the discarded items in a [`u128`].
+/// Returns the first item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`], but allows counting beyond `usize::MAX`.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `u128::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `u128::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_u128;
+/// let mut range = 1..=10;
+/// let result = discard_while_u128(&mut range, |&n| n != 5);
+/// assert_eq!(result, (Some(5), 4u128));
+/// assert_eq!(range, 6..=10);
+/// ```
+pub fn discard_while_u128<T>(
+    iter: impl IntoIterator<Item = T>,
+    cond: impl FnMut(&T) -> bool,
+) -> (Option<T>, u128) {
+    discard_while_counting(iter, cond, 0, |i: u128| i + 1)
+}
+
+/// Why discarding stopped, as reported by [`Discarded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StopReason {
+    /// An item did not satisfy the condition.
+    Rejected,
+    /// The iterator ran out of items.
+    Exhausted,
+    /// The maximum number of items was discarded, see [`discard_while_limited`].
+    LimitReached,
+}
+
+/// The result of [`discard_while_detailed`] and [`discard_while_limited`].
+///
+/// Holds the first non-matching element, if any, the amount of discarded elements,
+/// and the [`StopReason`].
+/// It can be converted into the `(Option<T>, usize)` tuple returned by [`discard_while`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Discarded<T> {
+    stopper: Option<T>,
+    count: usize,
+    reason: StopReason,
+}
+
+impl<T> Discarded<T> {
+    /// Returns a reference to the first element that did not satisfy the condition, if any.
+    pub fn stopper(&self) -> Option<&T> {
+        self.stopper.as_ref()
+    }
+
+    /// Returns the first element that did not satisfy the condition, if any.
+    pub fn into_stopper(self) -> Option<T> {
+        self.stopper
+    }
+
+    /// Returns the number of elements that were discarded.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns why discarding stopped.
+    pub fn reason(&self) -> StopReason {
+        self.reason
+    }
+
+    /// Returns `true` if discarding stopped because an item did not satisfy the condition.
+    pub fn is_rejected(&self) -> bool {
+        self.reason == StopReason::Rejected
+    }
+
+    /// Returns `true` if discarding stopped because the iterator ran out of items.
+    pub fn is_exhausted(&self) -> bool {
+        self.reason == StopReason::Exhausted
+    }
+
+    /// Returns `true` if discarding stopped because the maximum number of items was discarded.
+    pub fn is_limit_reached(&self) -> bool {
+        self.reason == StopReason::LimitReached
+    }
+
+    /// Converts this into the tuple returned by [`discard_while`].
+    pub fn into_parts(self) -> (Option<T>, usize) {
+        (self.stopper, self.count)
     }
 }
 
-impl<T: Iterator> DiscardWhile for T {}
+impl<T> From<Discarded<T>> for (Option<T>, usize) {
+    fn from(discarded: Discarded<T>) -> Self {
+        discarded.into_parts()
+    }
+}
+
+impl<T> From<(Option<T>, usize)> for Discarded<T> {
+    fn from((stopper, count): (Option<T>, usize)) -> Self {
+        let reason = match stopper {
+            Some(_) => StopReason::Rejected,
+            None => StopReason::Exhausted,
+        };
+        Discarded {
+            stopper,
+            count,
+            reason,
+        }
+    }
+}
+
+/// Advance an iterator as long as a condition on the yielded items holds.
+/// Returns a [`Discarded`] holding the first item that no longer satisfies the condition,
+/// if any, the number of items discarded, and why discarding stopped.
+///
+/// This works like [`discard_while`], but returns a structured result.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::{discard_while_detailed, StopReason};
+/// let mut range = 1..=10;
+/// let result = discard_while_detailed(&mut range, |&n| n != 5);
+/// assert_eq!(result.reason(), StopReason::Rejected);
+/// assert_eq!(result.stopper(), Some(&5));
+/// assert_eq!(result.count(), 4);
+/// assert_eq!(range, 6..=10);
+/// ```
+///
+/// If the iterator ends before an item that does not fulfill the condition
+/// is encountered, the reason is [`StopReason::Exhausted`].
+///
+/// ```
+/// # use discard_while::discard_while_detailed;
+/// let result = discard_while_detailed([1, 2, 3], |_| true);
+/// assert!(result.is_exhausted());
+/// assert_eq!(result.into_parts(), (None, 3));
+/// ```
+pub fn discard_while_detailed<T>(
+    iter: impl IntoIterator<Item = T>,
+    cond: impl FnMut(&T) -> bool,
+) -> Discarded<T> {
+    discard_while(iter, cond).into()
+}
+
+/// Advance an iterator as long as a condition on the yielded items holds,
+/// discarding at most `limit` items.
+/// Returns a [`Discarded`] holding the first item that no longer satisfies the condition,
+/// if any, the number of items discarded, and why discarding stopped.
+///
+/// This works like [`discard_while_detailed`], but stops with [`StopReason::LimitReached`]
+/// once `limit` items have been discarded, without pulling another item from the iterator.
+/// This makes it safe to use on iterators that might never yield a non-matching item.
+///
+/// If the iterator ends right after `limit` discarded items,
+/// the reason is still [`StopReason::LimitReached`], since the end was not observed.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::{discard_while_limited, StopReason};
+/// let mut range = 1..=10;
+/// let result = discard_while_limited(&mut range, 8, |&n| n != 5);
+/// assert_eq!(result.reason(), StopReason::Rejected);
+/// assert_eq!(result.into_parts(), (Some(5), 4));
+/// assert_eq!(range, 6..=10);
+/// ```
+///
+/// If the limit is reached, no further item is taken from the iterator.
+///
+/// ```
+/// # use discard_while::{discard_while_limited, StopReason};
+/// let mut iter = core::iter::repeat(0);
+/// let result = discard_while_limited(&mut iter, 3, |&n| n == 0);
+/// assert_eq!(result.reason(), StopReason::LimitReached);
+/// assert_eq!(result.into_parts(), (None, 3));
+/// ```
+pub fn discard_while_limited<T>(
+    iter: impl IntoIterator<Item = T>,
+    limit: usize,
+    cond: impl FnMut(&T) -> bool,
+) -> Discarded<T> {
+    let mut iter = iter.into_iter();
+    let (stopper, count) = discard_while(iter.by_ref().take(limit), cond);
+    let reason = match stopper {
+        Some(_) => StopReason::Rejected,
+        None if count == limit => StopReason::LimitReached,
+        None => StopReason::Exhausted,
+    };
+    Discarded {
+        stopper,
+        count,
+        reason,
+    }
+}
+
+/// Advance an iterator as long as a condition on the yielded items and their indices holds.
+/// Returns the first item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`], but the condition also receives the index of the item,
+/// which is the number of items discarded before it.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_indexed;
+/// let mut iter = [0, 1, 2, 4, 5].into_iter();
+/// let result = discard_while_indexed(&mut iter, |i, &n| n == i);
+/// assert_eq!(result, (Some(4), 3));
+/// assert_eq!(iter.next(), Some(5));
+/// ```
+///
+/// The index can be used to bound the number of discarded items.
+///
+/// ```
+/// # use discard_while::discard_while_indexed;
+/// let lines = ["# a", "# b", "# c", "data"];
+/// let result = discard_while_indexed(lines, |i, line| i < 2 && line.starts_with('#'));
+/// assert_eq!(result, (Some("# c"), 2));
+/// ```
+pub fn discard_while_indexed<T>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(usize, &T) -> bool,
+) -> (Option<T>, usize) {
+    let flow = iter.into_iter().try_fold(0, |i, next| {
+        if cond(i, &next) {
+            ControlFlow::Continue(i + 1)
+        } else {
+            ControlFlow::Break((next, i))
+        }
+    });
+    match flow {
+        ControlFlow::Continue(i) => (None, i),
+        ControlFlow::Break((next, i)) => (Some(next), i),
+    }
+}
+
+/// Advance an iterator as long as a condition on each yielded item and the one before it holds.
+/// Returns the first item that no longer satisfies the condition, if any,
+/// the number of items discarded, and the last discarded item, if any.
+///
+/// The first item is always discarded, since there is no item before it.
+/// Every following item is discarded as long as the condition holds
+/// for the previous item and that item, in that order.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_pairwise;
+/// let mut iter = [1, 2, 2, 5, 3, 4].into_iter();
+/// let result = discard_while_pairwise(&mut iter, |prev, next| prev <= next);
+/// assert_eq!(result, (Some(3), 4, Some(5)));
+/// assert_eq!(iter.next(), Some(4));
+/// ```
+///
+/// If the iterator is empty, nothing is discarded.
+///
+/// ```
+/// # use discard_while::discard_while_pairwise;
+/// let result = discard_while_pairwise(Vec::<i32>::new(), |prev, next| prev <= next);
+/// assert_eq!(result, (None, 0, None));
+/// ```
+pub fn discard_while_pairwise<T>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(&T, &T) -> bool,
+) -> (Option<T>, usize, Option<T>) {
+    let mut iter = iter.into_iter();
+    let Some(first) = iter.next() else {
+        return (None, 0, None);
+    };
+    let flow = iter.try_fold((first, 1), |(prev, i), next| {
+        if cond(&prev, &next) {
+            ControlFlow::Continue((next, i + 1))
+        } else {
+            ControlFlow::Break((prev, next, i))
+        }
+    });
+    match flow {
+        ControlFlow::Continue((last, i)) => (None, i, Some(last)),
+        ControlFlow::Break((last, next, i)) => (Some(next), i, Some(last)),
+    }
+}
+
+/// Advance an iterator as long as a mapping of the yielded items returns [`None`].
+/// Returns the first value the mapping returns, if any,
+/// and the number of items discarded.
+///
+/// This is similar to [`find_map`], but also counts the discarded items.
+/// Since the mapping takes the items by value, the first successfully mapped
+/// item does not need to be cloned or mapped again.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_map;
+/// let mut iter = ["a", "b", "12", "c"].into_iter();
+/// let result = discard_while_map(&mut iter, |s| s.parse::<u32>().ok());
+/// assert_eq!(result, (Some(12), 2));
+/// assert_eq!(iter.next(), Some("c"));
+/// ```
+///
+/// If the mapping returns [`None`] for every item, [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::discard_while_map;
+/// let result = discard_while_map(["a", "b"], |s| s.parse::<u32>().ok());
+/// assert_eq!(result, (None, 2));
+/// ```
+///
+/// [`find_map`]: Iterator::find_map
+pub fn discard_while_map<T, U>(
+    iter: impl IntoIterator<Item = T>,
+    mut f: impl FnMut(T) -> Option<U>,
+) -> (Option<U>, usize) {
+    let flow = iter.into_iter().try_fold(0, |i, next| match f(next) {
+        None => ControlFlow::Continue(i + 1),
+        Some(mapped) => ControlFlow::Break((mapped, i)),
+    });
+    match flow {
+        ControlFlow::Continue(i) => (None, i),
+        ControlFlow::Break((mapped, i)) => (Some(mapped), i),
+    }
+}
+
+/// Advance an iterator as long as a condition taking the yielded items by value
+/// returns [`Ok`].
+/// Returns the value of the first [`Err`] the condition returns, if any,
+/// and the number of items discarded.
+///
+/// Since the condition owns each item, it can consume it,
+/// or move or transform it into the [`Err`] value to stop with.
+/// Values returned in [`Ok`] are dropped.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_by_value;
+/// let buffers = vec![String::new(), String::from("  "), String::from(" hi ")];
+/// let result = discard_while_by_value(buffers, |buf| {
+///     if buf.trim().is_empty() {
+///         Ok(buf)
+///     } else {
+///         Err(buf.trim().to_owned())
+///     }
+/// });
+/// assert_eq!(result, (Some(String::from("hi")), 2));
+/// ```
+///
+/// If the condition returns [`Ok`] for every item,
+/// [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::discard_while_by_value;
+/// let result = discard_while_by_value([1, 2, 3], |n| if n > 5 { Err(n) } else { Ok(()) });
+/// assert_eq!(result, (None, 3));
+/// ```
+pub fn discard_while_by_value<T, D, U>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(T) -> Result<D, U>,
+) -> (Option<U>, usize) {
+    let flow = iter.into_iter().try_fold(0, |i, next| match cond(next) {
+        Ok(_) => ControlFlow::Continue(i + 1),
+        Err(stopper) => ControlFlow::Break((stopper, i)),
+    });
+    match flow {
+        ControlFlow::Continue(i) => (None, i),
+        ControlFlow::Break((stopper, i)) => (Some(stopper), i),
+    }
+}
+
+/// Advance an iterator as long as a condition on the yielded items holds.
+/// Returns the first item that no longer satisfies the condition, if any,
+/// the number of items discarded, and the last discarded item, if any.
+///
+/// This works like [`discard_while`], but keeps the last discarded item
+/// instead of dropping it, so the boundary between matching and non-matching items
+/// is available without requiring [`Clone`].
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_last;
+/// let mut range = 1..=10;
+/// let result = discard_while_last(&mut range, |&n| n * n < 30);
+/// assert_eq!(result, (Some(6), 5, Some(5)));
+/// assert_eq!(range, 7..=10);
+/// ```
+///
+/// If the first item does not fulfill the condition, no item was discarded.
+///
+/// ```
+/// # use discard_while::discard_while_last;
+/// let result = discard_while_last([3, 2, 1], |&n| n < 3);
+/// assert_eq!(result, (Some(3), 0, None));
+/// ```
+pub fn discard_while_last<T>(
+    iter: impl IntoIterator<Item = T>,
+    mut cond: impl FnMut(&T) -> bool,
+) -> (Option<T>, usize, Option<T>) {
+    let flow = iter.into_iter().try_fold((None, 0), |(last, i), next| {
+        if cond(&next) {
+            ControlFlow::Continue((Some(next), i + 1))
+        } else {
+            ControlFlow::Break((last, next, i))
+        }
+    });
+    match flow {
+        ControlFlow::Continue((last, i)) => (None, i, last),
+        ControlFlow::Break((last, next, i)) => (Some(next), i, last),
+    }
+}
+
+/// Advance an iterator past all items satisfying a monotone condition,
+/// using an exponential search instead of checking every item.
+/// Returns the first item that does not satisfy the condition, if any,
+/// and the number of items discarded.
+///
+/// The condition must be monotone, that is, once it is `false` for an item,
+/// it must be `false` for all following items, like for sorted data.
+/// If it is, the result is the same as with [`discard_while`],
+/// but the condition is only called `O(log n)` times, where `n` is the number of discarded items.
+///
+/// Items are looked up by cloning the iterator and calling [`nth`] on the clone,
+/// and the iterator itself is advanced with a single call to [`nth`].
+/// This is only efficient for iterators where cloning is cheap and [`nth`] takes constant time,
+/// like slice iterators and ranges.
+///
+/// # Monotonicity
+///
+/// If the condition is not monotone, the result is unspecified,
+/// but the function does not panic or loop forever.
+/// Use [`discard_while_sorted_checked`] to detect conditions that are not monotone.
+///
+/// # Overflow Behavior
+///
+/// The search stops at position `usize::MAX`, and the item there is checked once more
+/// before it is returned, so if there are more than `usize::MAX` non-matching elements,
+/// a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function panics if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_sorted;
+/// let sorted: Vec<u32> = (0..1000).map(|n| n * 3).collect();
+/// let mut iter = sorted.iter();
+/// let mut calls = 0;
+/// let result = discard_while_sorted(&mut iter, |&&n| {
+///     calls += 1;
+///     n < 1500
+/// });
+/// assert_eq!(result, (Some(&1500), 500));
+/// assert_eq!(iter.next(), Some(&1503));
+/// assert!(calls < 25);
+/// ```
+///
+/// If the condition holds for all items, [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::discard_while_sorted;
+/// let result = discard_while_sorted(&mut (1..=100), |&n| n > 0);
+/// assert_eq!(result, (None, 100));
+/// ```
+///
+/// [`nth`]: Iterator::nth
+pub fn discard_while_sorted<I: Iterator + Clone>(
+    iter: &mut I,
+    mut cond: impl FnMut(&I::Item) -> bool,
+) -> (Option<I::Item>, usize) {
+    let (count, _) = gallop(|n| iter.clone().nth(n).is_some_and(|item| cond(&item)));
+    take_stopper(iter, count, cond)
+}
+
+/// Like [`discard_while_sorted`], but checks that the condition is monotone.
+///
+/// After the search, the condition is called once more for each item up to the last probed one,
+/// which is at most twice as far as the first item that does not satisfy the condition.
+/// This makes the function take `O(n)` time again, so it is meant for tests and debugging.
+///
+/// # Panics
+///
+/// This function panics if the condition is not monotone on the checked items,
+/// or if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_sorted_checked;
+/// let mut range = 0..1000;
+/// assert_eq!(discard_while_sorted_checked(&mut range, |&n| n < 300), (Some(300), 300));
+/// assert_eq!(range, 301..1000);
+/// ```
+///
+/// A condition that is not monotone causes a panic:
+///
+/// ```should_panic
+/// # use discard_while::discard_while_sorted_checked;
+/// discard_while_sorted_checked(&mut (0..1000), |&n| n < 300 || n == 400);
+/// ```
+pub fn discard_while_sorted_checked<I: Iterator + Clone>(
+    iter: &mut I,
+    mut cond: impl FnMut(&I::Item) -> bool,
+) -> (Option<I::Item>, usize) {
+    let (count, end) = gallop(|n| iter.clone().nth(n).is_some_and(|item| cond(&item)));
+    let monotone = iter
+        .clone()
+        .take(end.saturating_add(1))
+        .enumerate()
+        .all(|(i, item)| cond(&item) == (i < count));
+    assert!(
+        monotone,
+        "condition passed to discard_while_sorted_checked is not monotone"
+    );
+    take_stopper(iter, count, cond)
+}
+
+/// Advances the iterator past the `count` items found by [`gallop`] and returns the next one.
+///
+/// If the search stopped at `usize::MAX`, that item might still satisfy the condition,
+/// so it is checked before it is returned.
+fn take_stopper<I: Iterator>(
+    iter: &mut I,
+    count: usize,
+    mut cond: impl FnMut(&I::Item) -> bool,
+) -> (Option<I::Item>, usize) {
+    let stopper = iter.nth(count);
+    assert!(
+        count < usize::MAX || !stopper.as_ref().is_some_and(&mut cond),
+        "more than usize::MAX items satisfy the condition"
+    );
+    (stopper, count)
+}
+
+/// The exponential search shared by [`discard_while_sorted`] and [`discard_while_position_monotone`].
+///
+/// Returns the first position for which `probe` returns `false`, assuming that it does so
+/// for all following positions, and the last position that was probed.
+/// If `probe` returns `true` for `usize::MAX`, both are `usize::MAX`.
+fn gallop(mut probe: impl FnMut(usize) -> bool) -> (usize, usize) {
+    // All positions before `lo` satisfy the condition,
+    // and the position `hi` does not.
+    let mut lo: usize = 0;
+    let mut step: usize = 1;
+    let mut hi = loop {
+        let n = lo.saturating_add(step - 1);
+        if !probe(n) {
+            break n;
+        }
+        if n == usize::MAX {
+            return (n, n);
+        }
+        lo = n + 1;
+        step = step.saturating_mul(2);
+    };
+    let end = hi;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if probe(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo, end)
+}
+
+/// Advance an iterator as long as a condition on the positions of the items holds.
+/// Returns the first item whose position no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// Unlike [`discard_while_indexed`], the condition only receives the position,
+/// so the discarded items never need to be produced.
+/// The condition is checked for the positions of the items known to exist from the iterator's
+/// [`size_hint`] first, and those that are discarded are skipped with a single call to [`nth`],
+/// which takes constant time for iterators like slice iterators and ranges.
+/// Any further items are checked and skipped one at a time.
+///
+/// If the condition is monotone, like `|i| i < k`,
+/// [`discard_while_position_monotone`] only calls it `O(log n)` times.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_position;
+/// let mut iter = [10, 20, 30, 40, 50].iter();
+/// let result = discard_while_position(&mut iter, |i| i < 2);
+/// assert_eq!(result, (Some(&30), 2));
+/// assert_eq!(iter.as_slice(), [40, 50]);
+/// ```
+///
+/// The condition does not need to be monotone, so it can skip to an aligned position:
+///
+/// ```
+/// # use discard_while::discard_while_position;
+/// let offset = 3;
+/// let mut range = 0..100;
+/// let result = discard_while_position(&mut range, |i| !(offset + i).is_multiple_of(8));
+/// assert_eq!(result, (Some(5), 5));
+/// assert_eq!(range, 6..100);
+/// ```
+///
+/// If the condition holds for every position, all items are discarded
+/// and [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::discard_while_position;
+/// let data = [0u8; 10];
+/// let result = discard_while_position(&data, |_| true);
+/// assert_eq!(result, (None, 10));
+/// ```
+///
+/// [`nth`]: Iterator::nth
+/// [`size_hint`]: Iterator::size_hint
+pub fn discard_while_position<I: IntoIterator>(
+    iter: I,
+    mut cond: impl FnMut(usize) -> bool,
+) -> (Option<I::Item>, usize) {
+    let mut iter = iter.into_iter();
+    let (lower, _) = iter.size_hint();
+    let mut count = 0;
+    while count < lower && cond(count) {
+        count += 1;
+    }
+    if count < lower {
+        return (iter.nth(count), count);
+    }
+    if count > 0 {
+        iter.nth(count - 1);
+    }
+    let stopper = loop {
+        match iter.next() {
+            Some(_) if cond(count) => count += 1,
+            item => break item,
+        }
+    };
+    (stopper, count)
+}
+
+/// Advance an iterator as long as a monotone condition on the positions of the items holds.
+/// Returns the first item whose position no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while_position`], but the condition must be monotone,
+/// that is, once it is `false` for a position, it must be `false` for all following positions,
+/// like `|i| i < k`.
+/// The first position for which it is `false` is then found with an exponential search,
+/// calling the condition only `O(log n)` times, where `n` is the number of discarded items.
+///
+/// The items known to exist from the iterator's [`size_hint`] are skipped with a single call to [`nth`],
+/// which takes constant time for iterators like slice iterators and ranges.
+/// Any further items are skipped one at a time.
+///
+/// # Monotonicity
+///
+/// If the condition is not monotone, the result is unspecified,
+/// but the function does not panic or loop forever.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_position_monotone;
+/// let mut iter = [10, 20, 30, 40, 50].iter();
+/// let result = discard_while_position_monotone(&mut iter, |i| i < 2);
+/// assert_eq!(result, (Some(&30), 2));
+/// assert_eq!(iter.as_slice(), [40, 50]);
+/// ```
+///
+/// If the condition holds for every position, all items are discarded
+/// and [`None`] is returned as the first return value.
+/// This only calls the condition a few dozen times, even on the longest ranges.
+///
+/// ```
+/// # use discard_while::discard_while_position_monotone;
+/// let mut calls = 0;
+/// let result = discard_while_position_monotone(1..=u32::MAX, |_| {
+///     calls += 1;
+///     true
+/// });
+/// assert_eq!(result, (None, u32::MAX as usize));
+/// assert!(calls < 200);
+/// ```
+///
+/// [`nth`]: Iterator::nth
+/// [`size_hint`]: Iterator::size_hint
+pub fn discard_while_position_monotone<I: IntoIterator>(
+    iter: I,
+    mut cond: impl FnMut(usize) -> bool,
+) -> (Option<I::Item>, usize) {
+    let mut iter = iter.into_iter();
+    let (lower, upper) = iter.size_hint();
+    // If the length is known, positions past the end are never passed to the condition.
+    let len = if upper == Some(lower) {
+        lower
+    } else {
+        usize::MAX
+    };
+    let (target, _) = gallop(|n| n < len && cond(n));
+    let mut count = target.min(lower);
+    if count > 0 {
+        iter.nth(count - 1);
+    }
+    let stopper = loop {
+        match iter.next() {
+            Some(item) if count == target => break Some(item),
+            Some(_) => count += 1,
+            None => break None,
+        }
+    };
+    (stopper, count)
+}
+
+/// Advance a [`Peekable`] iterator as long as a condition on the yielded items holds,
+/// leaving the first item that no longer satisfies the condition in place.
+/// Returns a reference to that item, if any, and the number of items discarded.
+///
+/// Unlike [`discard_while`], the first non-matching item is not consumed,
+/// so it is the next item yielded by the iterator.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_peeking;
+/// let mut chars = "   foo".chars().peekable();
+/// let result = discard_while_peeking(&mut chars, |c| c.is_whitespace());
+/// assert_eq!(result, (Some(&'f'), 3));
+/// assert_eq!(chars.collect::<String>(), "foo");
+/// ```
+///
+/// If the iterator ends before an item that does not fulfill the condition
+/// is encountered, [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::discard_while_peeking;
+/// let mut chars = "   ".chars().peekable();
+/// let result = discard_while_peeking(&mut chars, |c| c.is_whitespace());
+/// assert_eq!(result, (None, 3));
+/// ```
+pub fn discard_while_peeking<I: Iterator>(
+    iter: &mut Peekable<I>,
+    mut cond: impl FnMut(&I::Item) -> bool,
+) -> (Option<&I::Item>, usize) {
+    let mut i = 0;
+    while iter.next_if(&mut cond).is_some() {
+        i += 1;
+    }
+    (iter.peek(), i)
+}
+
+/// Advance a [`DoubleEndedIterator`] from the back as long as a condition on the yielded items holds.
+/// Returns the last item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`], but takes items from the back using
+/// [`next_back`], so the front of the iterator stays untouched.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::discard_while_back;
+/// let mut range = 1..=10;
+/// let result = discard_while_back(&mut range, |&n| n != 5);
+/// assert_eq!(result, (Some(5), 5));
+/// assert_eq!(range, 1..=4);
+/// ```
+///
+/// [`next_back`]: DoubleEndedIterator::next_back
+pub fn discard_while_back<I: IntoIterator>(
+    iter: I,
+    cond: impl FnMut(&I::Item) -> bool,
+) -> (Option<I::Item>, usize)
+where
+    I::IntoIter: DoubleEndedIterator,
+{
+    discard_while(iter.into_iter().rev(), cond)
+}
+
+/// The result of [`trim_while`].
+///
+/// Holds the first and last non-matching elements, if any,
+/// and the amount of elements discarded from the front and the back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trimmed<T> {
+    /// The first element from the front that did not satisfy the condition, if any.
+    pub front: Option<T>,
+    /// The number of elements discarded from the front.
+    pub front_count: usize,
+    /// The first element from the back that did not satisfy the condition, if any.
+    pub back: Option<T>,
+    /// The number of elements discarded from the back.
+    pub back_count: usize,
+}
+
+/// Advance a [`DoubleEndedIterator`] from both ends as long as a condition on the yielded items holds.
+/// Returns the items from the front and the back that no longer satisfy the condition, if any,
+/// and the number of items discarded from each end.
+///
+/// The front is discarded first, as with [`discard_while`], then the back,
+/// as with [`discard_while_back`].
+/// If only one non-matching item remains, it is returned as [`front`](Trimmed::front),
+/// and [`back`](Trimmed::back) is [`None`].
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements at either end, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements
+/// at either end.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::{trim_while, Trimmed};
+/// let mut iter = [0, 0, 1, 2, 3, 0].into_iter();
+/// let result = trim_while(&mut iter, |&n| n == 0);
+/// assert_eq!(
+///     result,
+///     Trimmed { front: Some(1), front_count: 2, back: Some(3), back_count: 1 },
+/// );
+/// assert_eq!(iter.as_slice(), [2]);
+/// ```
+///
+/// If all items fulfill the condition, they are all discarded from the front.
+///
+/// ```
+/// # use discard_while::{trim_while, Trimmed};
+/// let result = trim_while([0, 0, 0], |&n| n == 0);
+/// assert_eq!(
+///     result,
+///     Trimmed { front: None, front_count: 3, back: None, back_count: 0 },
+/// );
+/// ```
+pub fn trim_while<I: IntoIterator>(
+    iter: I,
+    mut cond: impl FnMut(&I::Item) -> bool,
+) -> Trimmed<I::Item>
+where
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut iter = iter.into_iter();
+    let (front, front_count) = discard_while(&mut iter, &mut cond);
+    let (back, back_count) = discard_while_back(&mut iter, cond);
+    Trimmed {
+        front,
+        front_count,
+        back,
+        back_count,
+    }
+}
+
+/// Split a slice at the first element that does not satisfy a condition.
+/// Returns the discarded prefix, the first element that does not satisfy the condition,
+/// if any, and the remaining elements after it.
+///
+/// This works like [`discard_while`] on the slice's elements,
+/// with the length of the discarded prefix being the number of discarded elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::split_discard_while;
+/// let slice = [1, 2, 3, 4, 5];
+/// let result = split_discard_while(&slice, |&n| n < 3);
+/// assert_eq!(result, (&[1, 2][..], Some(&3), &[4, 5][..]));
+/// ```
+///
+/// If all elements fulfill the condition, the whole slice is discarded.
+///
+/// ```
+/// # use discard_while::split_discard_while;
+/// let slice = [1, 2, 3];
+/// let result = split_discard_while(&slice, |_| true);
+/// assert_eq!(result, (&[1, 2, 3][..], None, &[][..]));
+/// ```
+pub fn split_discard_while<T>(
+    slice: &[T],
+    mut cond: impl FnMut(&T) -> bool,
+) -> (&[T], Option<&T>, &[T]) {
+    let (_, count) = discard_while(slice, |item| cond(item));
+    let (discarded, rest) = slice.split_at(count);
+    match rest {
+        [stopper, rest @ ..] => (discarded, Some(stopper), rest),
+        [] => (discarded, None, &[]),
+    }
+}
+
+/// Split a mutable slice at the first element that does not satisfy a condition.
+/// Returns the discarded prefix, the first element that does not satisfy the condition,
+/// if any, and the remaining elements after it.
+///
+/// This is the mutable counterpart of [`split_discard_while`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::split_discard_while_mut;
+/// let mut slice = [0, 0, 1, 2];
+/// let (discarded, stopper, rest) = split_discard_while_mut(&mut slice, |&n| n == 0);
+/// discarded.fill(9);
+/// *stopper.unwrap() += 10;
+/// rest[0] = 3;
+/// assert_eq!(slice, [9, 9, 11, 3]);
+/// ```
+pub fn split_discard_while_mut<T>(
+    slice: &mut [T],
+    mut cond: impl FnMut(&T) -> bool,
+) -> (&mut [T], Option<&mut T>, &mut [T]) {
+    let (_, count) = discard_while(&*slice, |item| cond(item));
+    let (discarded, rest) = slice.split_at_mut(count);
+    match rest {
+        [stopper, rest @ ..] => (discarded, Some(stopper), rest),
+        [] => (discarded, None, &mut []),
+    }
+}
+
+/// Where two iterators diverge, as returned by [`mismatch`] and [`mismatch_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mismatch<A, B> {
+    /// Both iterators yielded an item, and the items differ.
+    Both(A, B),
+    /// Only the left iterator yielded an item, the right one ran out.
+    Left(A),
+    /// Only the right iterator yielded an item, the left one ran out.
+    Right(B),
+    /// Both iterators ran out at the same time.
+    Neither,
+}
+
+impl<A, B> Mismatch<A, B> {
+    /// Returns `true` if both iterators ran out at the same time,
+    /// so they yielded the same items.
+    pub fn is_neither(&self) -> bool {
+        matches!(self, Mismatch::Neither)
+    }
+
+    /// Converts this into the first differing items of both iterators, if any.
+    pub fn into_parts(self) -> (Option<A>, Option<B>) {
+        match self {
+            Mismatch::Both(a, b) => (Some(a), Some(b)),
+            Mismatch::Left(a) => (Some(a), None),
+            Mismatch::Right(b) => (None, Some(b)),
+            Mismatch::Neither => (None, None),
+        }
+    }
+}
+
+/// Advance two iterators in lockstep as long as their items are equal.
+/// Returns where they diverge, and the length of their common prefix.
+///
+/// This is like `std::mismatch` in C++.
+/// Both first differing items are returned, see [`Mismatch`].
+/// If one iterator runs out first, the other one is advanced once more
+/// to return its next item.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// equal elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterators have more than `usize::MAX` equal elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::{mismatch, Mismatch};
+/// let result = mismatch("hello".chars(), "help".chars());
+/// assert_eq!(result, (Mismatch::Both('l', 'p'), 3));
+/// ```
+///
+/// If one iterator is a prefix of the other, the next item of the longer one is returned.
+///
+/// ```
+/// # use discard_while::{mismatch, Mismatch};
+/// assert_eq!(mismatch([1, 2], [1, 2, 3]), (Mismatch::Right(3), 2));
+/// assert_eq!(mismatch([1, 2], [1, 2]), (Mismatch::Neither, 2));
+/// ```
+pub fn mismatch<A, B>(a: A, b: B) -> (Mismatch<A::Item, B::Item>, usize)
+where
+    A: IntoIterator,
+    B: IntoIterator,
+    A::Item: PartialEq<B::Item>,
+{
+    mismatch_by(a, b, |a, b| a == b)
+}
+
+/// Advance two iterators in lockstep as long as their items are equal
+/// according to a custom equality.
+/// Returns where they diverge, and the length of their common prefix.
+///
+/// This works like [`mismatch`], but compares the items with `eq`.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// equal elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterators have more than `usize::MAX` equal elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::{mismatch_by, Mismatch};
+/// let result = mismatch_by(["a", "B", "c"], ["A", "b", "d"], |a, b| a.eq_ignore_ascii_case(b));
+/// assert_eq!(result, (Mismatch::Both("c", "d"), 2));
+/// ```
+pub fn mismatch_by<A: IntoIterator, B: IntoIterator>(
+    a: A,
+    b: B,
+    mut eq: impl FnMut(&A::Item, &B::Item) -> bool,
+) -> (Mismatch<A::Item, B::Item>, usize) {
+    let mut b = b.into_iter();
+    let flow = a.into_iter().try_fold(0, |i, a| match b.next() {
+        Some(b) if eq(&a, &b) => ControlFlow::Continue(i + 1),
+        Some(b) => ControlFlow::Break((Mismatch::Both(a, b), i)),
+        None => ControlFlow::Break((Mismatch::Left(a), i)),
+    });
+    match flow {
+        ControlFlow::Continue(i) => (b.next().map_or(Mismatch::Neither, Mismatch::Right), i),
+        ControlFlow::Break(result) => result,
+    }
+}
+
+/// Convenience trait to allow using [`discard_while`] as a method.
+/// This trait is implemented for every [`Iterator`].
+pub trait DiscardWhile: Iterator {
+    /// Advance the iterator as long as a condition on the yielded items holds.
+    /// Returns the first item that no longer satisfies the condition, if any,
+    /// and the number of items discarded.
+    ///
+    /// This is similar to a combination of [`find`] and [`position`].
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so if there are more than `usize::MAX`
+    /// non-matching elements, it either produces the wrong result or panics.
+    /// If overflow checks are enabled, a panic is guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// let result = range.discard_while(|&n| n != 5);
+    /// assert_eq!(result, (Some(5), 4));
+    /// assert_eq!(range, 6..=10);
+    /// ```
+    ///
+    /// If the iterator ends before an item that does not fulfill the condition
+    /// is encountered, [`None`] is returned as the first return value.
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// let result = range.discard_while(|&n| true);
+    /// assert_eq!(result, (None, 10));
+    /// assert!(range.is_empty());
+    /// ```
+    /// If the first element that is encountered does not fulfill the condition,
+    /// `0` is returned as the second return value.
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// let result = range.discard_while(|&n| false);
+    /// assert_eq!(result, (Some(1), 0));
+    /// assert_eq!(range, 2..=10);
+    /// ```
+    /// [`find`]: Iterator::find
+    /// [`position`]: Iterator::position
+    fn discard_while(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: Sized,
+    {
+        discard_while(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// taking it by value and returning it along with the result.
+    ///
+    /// See [`discard_while_owned`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let result = vec![1, 2, 3, 4].into_iter().discard_while_owned(|&n| n != 2);
+    /// assert_eq!(result.stopper, Some(2));
+    /// assert_eq!(result.count, 1);
+    /// assert_eq!(result.rest.as_slice(), [3, 4]);
+    /// ```
+    fn discard_while_owned(self, cond: impl FnMut(&Self::Item) -> bool) -> DiscardedOwned<Self>
+    where
+        Self: Sized,
+    {
+        discard_while_owned(self, cond)
+    }
+
+    /// Advance the iterator as long as a fallible condition on the yielded items holds.
+    ///
+    /// See [`try_discard_while`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = ["7", "8", "nine"].into_iter();
+    /// let err = iter.try_discard_while(|s| s.parse::<u8>().map(|_| true)).unwrap_err();
+    /// assert_eq!((err.item, err.count), ("nine", 2));
+    /// ```
+    fn try_discard_while<E>(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> Result<bool, E>,
+    ) -> TryDiscarded<Self::Item, E>
+    where
+        Self: Sized,
+    {
+        try_discard_while(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// stopping instead of overflowing the discard count.
+    ///
+    /// See [`checked_discard_while`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// assert_eq!(range.checked_discard_while(|&n| n < 4), Ok((Some(4), 3)));
+    /// ```
+    fn checked_discard_while(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> CheckedDiscarded<Self::Item>
+    where
+        Self: Sized,
+    {
+        checked_discard_while(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// saturating the discard count at `usize::MAX`.
+    ///
+    /// See [`saturating_discard_while`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// assert_eq!(range.saturating_discard_while(|&n| n < 4), (Some(4), 3));
+    /// ```
+    fn saturating_discard_while(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: Sized,
+    {
+        saturating_discard_while(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// counting the discarded items in a [`u64`].
+    ///
+    /// See [`discard_while_u64`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// assert_eq!(range.discard_while_u64(|&n| n < 4), (Some(4), 3));
+    /// ```
+    fn discard_while_u64(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, u64)
+    where
+        Self: Sized,
+    {
+        discard_while_u64(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// counting the discarded items in a [`u128`].
+    ///
+    /// See [`discard_while_u128`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// assert_eq!(range.discard_while_u128(|&n| n < 4), (Some(4), 3));
+    /// ```
+    fn discard_while_u128(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, u128)
+    where
+        Self: Sized,
+    {
+        discard_while_u128(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// returning a structured result.
+    ///
+    /// See [`discard_while_detailed`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// let result = range.discard_while_detailed(|&n| n < 4);
+    /// assert!(result.is_rejected());
+    /// assert_eq!(<(Option<_>, usize)>::from(result), (Some(4), 3));
+    /// ```
+    fn discard_while_detailed(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> Discarded<Self::Item>
+    where
+        Self: Sized,
+    {
+        discard_while_detailed(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// pushing every discarded item into a [`Sink`].
+    ///
+    /// See [`discard_while_into`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::{DiscardWhile, Extending};
+    /// let mut discarded = Extending(String::new());
+    /// let mut chars = "ab:cd".chars();
+    /// assert_eq!(chars.discard_while_into(&mut discarded, |&c| c != ':'), Ok((Some(':'), 2)));
+    /// assert_eq!(discarded.0, "ab");
+    /// ```
+    fn discard_while_into(
+        &mut self,
+        sink: impl Sink<Self::Item>,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> DiscardedInto<Self::Item>
+    where
+        Self: Sized,
+    {
+        discard_while_into(self, sink, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// leaving the first item that no longer satisfies the condition in place.
+    /// Returns a reference to that item, if any, and the number of items discarded.
+    ///
+    /// This method is only available on [`Peekable`] iterators.
+    /// See [`discard_while_peeking`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = [0, 0, 1, 2].into_iter().peekable();
+    /// assert_eq!(iter.discard_while_peeking(|&n| n == 0), (Some(&1), 2));
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    fn discard_while_peeking(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<&Self::Item>, usize)
+    where
+        Self: sealed::IsPeekable,
+    {
+        discard_while_peeking(self.as_peekable(), cond)
+    }
+
+    /// Advance the iterator from the back as long as a condition on the yielded items holds.
+    ///
+    /// See [`discard_while_back`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..=10;
+    /// assert_eq!(range.discard_while_back(|&n| n > 7), (Some(7), 3));
+    /// assert_eq!(range, 1..=6);
+    /// ```
+    fn discard_while_back(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: DoubleEndedIterator + Sized,
+    {
+        discard_while_back(self, cond)
+    }
+
+    /// Advance the iterator from both ends as long as a condition on the yielded items holds.
+    ///
+    /// See [`trim_while`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut chars = "  foo ".chars();
+    /// let result = chars.trim_while(|c| c.is_whitespace());
+    /// assert_eq!((result.front, result.front_count), (Some('f'), 2));
+    /// assert_eq!((result.back, result.back_count), (Some('o'), 1));
+    /// assert_eq!(chars.as_str(), "o");
+    /// ```
+    fn trim_while(&mut self, cond: impl FnMut(&Self::Item) -> bool) -> Trimmed<Self::Item>
+    where
+        Self: DoubleEndedIterator + Sized,
+    {
+        trim_while(self, cond)
+    }
+
+    /// Creates an iterator that skips elements while a condition holds,
+    /// and records how many elements it skipped.
+    ///
+    /// This works like [`skip_while`], but the number of skipped elements
+    /// is available from [`SkipWhileCounted::skipped`]
+    /// once the first element has been yielded.
+    /// Unlike [`discard_while`], nothing happens until the adapter is advanced.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so if there are more than `usize::MAX`
+    /// non-matching elements, it either produces the wrong result or panics.
+    /// If overflow checks are enabled, a panic is guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// The adapter might panic if the iterator has more than `usize::MAX` non-matching elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = [0, 0, 1, 0, 2].into_iter().skip_while_counted(|&n| n == 0);
+    /// assert_eq!(iter.skipped(), None);
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.skipped(), Some(2));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [0, 2]);
+    /// ```
+    ///
+    /// Elements can also be taken from the back.
+    /// The leading elements are skipped before the first one is yielded.
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = (1..=6).skip_while_counted(|&n| n < 3);
+    /// assert_eq!(iter.next_back(), Some(6));
+    /// assert_eq!(iter.skipped(), Some(2));
+    /// assert_eq!(iter.size_hint(), (3, Some(3)));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [3, 4, 5]);
+    /// ```
+    ///
+    /// [`skip_while`]: Iterator::skip_while
+    fn skip_while_counted<P>(self, cond: P) -> SkipWhileCounted<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        SkipWhileCounted::new(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// discarding at most `limit` items.
+    ///
+    /// See [`discard_while_limited`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 1..;
+    /// let result = range.discard_while_limited(100, |&n| n > 0);
+    /// assert!(result.is_limit_reached());
+    /// assert_eq!(result.count(), 100);
+    /// assert_eq!(range.next(), Some(101));
+    /// ```
+    fn discard_while_limited(
+        &mut self,
+        limit: usize,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> Discarded<Self::Item>
+    where
+        Self: Sized,
+    {
+        discard_while_limited(self, limit, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items and their indices holds.
+    ///
+    /// See [`discard_while_indexed`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = "aaaaab".chars();
+    /// assert_eq!(iter.discard_while_indexed(|i, &c| i < 3 && c == 'a'), (Some('a'), 3));
+    /// assert_eq!(iter.as_str(), "ab");
+    /// ```
+    fn discard_while_indexed(
+        &mut self,
+        cond: impl FnMut(usize, &Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: Sized,
+    {
+        discard_while_indexed(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on each yielded item and the one before it holds.
+    ///
+    /// See [`discard_while_pairwise`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = [7, 7, 7, 8, 7].into_iter();
+    /// assert_eq!(iter.discard_while_pairwise(|a, b| a == b), (Some(8), 3, Some(7)));
+    /// assert_eq!(iter.next(), Some(7));
+    /// ```
+    fn discard_while_pairwise(
+        &mut self,
+        cond: impl FnMut(&Self::Item, &Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize, Option<Self::Item>)
+    where
+        Self: Sized,
+    {
+        discard_while_pairwise(self, cond)
+    }
+
+    /// Advance the iterator as long as a mapping of the yielded items returns [`None`].
+    ///
+    /// See [`discard_while_map`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = "ab3c".chars();
+    /// assert_eq!(iter.discard_while_map(|c| c.to_digit(10)), (Some(3), 2));
+    /// assert_eq!(iter.as_str(), "c");
+    /// ```
+    fn discard_while_map<U>(&mut self, f: impl FnMut(Self::Item) -> Option<U>) -> (Option<U>, usize)
+    where
+        Self: Sized,
+    {
+        discard_while_map(self, f)
+    }
+
+    /// Advance the iterator as long as a condition taking the yielded items by value
+    /// returns [`Ok`].
+    ///
+    /// See [`discard_while_by_value`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = vec![vec![0], vec![], vec![1, 2]].into_iter();
+    /// let result = iter.discard_while_by_value(|v| match v.as_slice() {
+    ///     [0] | [] => Ok(v),
+    ///     _ => Err(v.into_boxed_slice()),
+    /// });
+    /// assert_eq!(result, (Some(vec![1, 2].into_boxed_slice()), 2));
+    /// ```
+    fn discard_while_by_value<D, U>(
+        &mut self,
+        cond: impl FnMut(Self::Item) -> Result<D, U>,
+    ) -> (Option<U>, usize)
+    where
+        Self: Sized,
+    {
+        discard_while_by_value(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the yielded items holds,
+    /// keeping the last discarded item.
+    ///
+    /// See [`discard_while_last`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = [10, 20, 30, 40].into_iter();
+    /// assert_eq!(iter.discard_while_last(|&n| n <= 25), (Some(30), 2, Some(20)));
+    /// ```
+    fn discard_while_last(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize, Option<Self::Item>)
+    where
+        Self: Sized,
+    {
+        discard_while_last(self, cond)
+    }
+
+    /// Advance the iterator past all items satisfying a monotone condition,
+    /// using an exponential search instead of checking every item.
+    ///
+    /// See [`discard_while_sorted`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 0..1_000_000;
+    /// assert_eq!(range.discard_while_sorted(|&n| n * n < 1_000_000), (Some(1000), 1000));
+    /// assert_eq!(range, 1001..1_000_000);
+    /// ```
+    fn discard_while_sorted(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: Clone + Sized,
+    {
+        discard_while_sorted(self, cond)
+    }
+
+    /// Advance the iterator past all items satisfying a monotone condition,
+    /// checking that the condition is monotone.
+    ///
+    /// See [`discard_while_sorted_checked`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut iter = [1, 3, 5, 8, 9].iter();
+    /// assert_eq!(iter.discard_while_sorted_checked(|&&n| n < 6), (Some(&8), 3));
+    /// assert_eq!(iter.next(), Some(&9));
+    /// ```
+    fn discard_while_sorted_checked(
+        &mut self,
+        cond: impl FnMut(&Self::Item) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: Clone + Sized,
+    {
+        discard_while_sorted_checked(self, cond)
+    }
+
+    /// Advance the iterator as long as a condition on the positions of the items holds.
+    ///
+    /// See [`discard_while_position`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 0..=100u32;
+    /// assert_eq!(range.discard_while_position(|i| i % 4 != 3), (Some(3), 3));
+    /// assert_eq!(range, 4..=100);
+    /// ```
+    fn discard_while_position(
+        &mut self,
+        cond: impl FnMut(usize) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: Sized,
+    {
+        discard_while_position(self, cond)
+    }
+
+    /// Advance the iterator as long as a monotone condition on the positions of the items holds.
+    ///
+    /// See [`discard_while_position_monotone`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::DiscardWhile;
+    /// let mut range = 0..=100u32;
+    /// assert_eq!(range.discard_while_position_monotone(|i| i < 8), (Some(8), 8));
+    /// assert_eq!(range, 9..=100);
+    /// ```
+    fn discard_while_position_monotone(
+        &mut self,
+        cond: impl FnMut(usize) -> bool,
+    ) -> (Option<Self::Item>, usize)
+    where
+        Self: Sized,
+    {
+        discard_while_position_monotone(self, cond)
+    }
+
+    /// Advance the iterator and another one in lockstep as long as their items are equal.
+    ///
+    /// See [`mismatch`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::{DiscardWhile, Mismatch};
+    /// let mut iter = [1, 2, 3, 4].into_iter();
+    /// assert_eq!(iter.mismatch([1, 2, 5]), (Mismatch::Both(3, 5), 2));
+    /// assert_eq!(iter.next(), Some(4));
+    /// ```
+    fn mismatch<B>(&mut self, other: B) -> (Mismatch<Self::Item, B::Item>, usize)
+    where
+        Self: Sized,
+        B: IntoIterator,
+        Self::Item: PartialEq<B::Item>,
+    {
+        mismatch(self, other)
+    }
+
+    /// Advance the iterator and another one in lockstep as long as their items are equal
+    /// according to a custom equality.
+    ///
+    /// See [`mismatch_by`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::{DiscardWhile, Mismatch};
+    /// let mut iter = [1.0f64, 2.0, 3.0].into_iter();
+    /// let result = iter.mismatch_by([1.01, 1.99], |a, b| (a - b).abs() < 0.05);
+    /// assert_eq!(result, (Mismatch::Left(3.0), 2));
+    /// ```
+    fn mismatch_by<B: IntoIterator>(
+        &mut self,
+        other: B,
+        eq: impl FnMut(&Self::Item, &B::Item) -> bool,
+    ) -> (Mismatch<Self::Item, B::Item>, usize)
+    where
+        Self: Sized,
+    {
+        mismatch_by(self, other, eq)
+    }
+}
+
+impl<T: Iterator> DiscardWhile for T {}
+
+mod sealed {
+    use core::iter::Peekable;
+
+    /// Implemented only for [`Peekable`], to make [`discard_while_peeking`] available
+    /// as a method of [`DiscardWhile`] without a separate trait.
+    ///
+    /// [`discard_while_peeking`]: crate::discard_while_peeking
+    /// [`DiscardWhile`]: crate::DiscardWhile
+    pub trait IsPeekable: Iterator {
+        /// The iterator wrapped by the [`Peekable`].
+        type Inner: Iterator<Item = Self::Item>;
+
+        fn as_peekable(&mut self) -> &mut Peekable<Self::Inner>;
+    }
+
+    impl<I: Iterator> IsPeekable for Peekable<I> {
+        type Inner = I;
+
+        fn as_peekable(&mut self) -> &mut Peekable<I> {
+            self
+        }
+    }
+}
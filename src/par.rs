@@ -0,0 +1,73 @@
+//! Support for Rayon's parallel iterators, enabled by the `rayon` feature.
+
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+/// Find the first item of an indexed parallel iterator that does not satisfy a condition,
+/// checking the items on all threads of Rayon's thread pool.
+/// Returns the first item that does not satisfy the condition, if any,
+/// and the number of items before it.
+///
+/// The result is the same as with [`discard_while`](crate::discard_while) on the items
+/// in sequence order, even though the condition may be called on items after the returned one.
+/// Since parallel iterators are consumed as a whole, the remaining items are dropped.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::par_discard_while;
+/// let data: Vec<u32> = (0..100_000).collect();
+/// let result = par_discard_while(&data, |&&n| n < 64_000);
+/// assert_eq!(result, (Some(&64_000), 64_000));
+/// ```
+///
+/// If all items fulfill the condition, [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::par_discard_while;
+/// let result = par_discard_while(0..1000, |&n| n < 1000);
+/// assert_eq!(result, (None, 1000));
+/// ```
+pub fn par_discard_while<I>(
+    iter: I,
+    cond: impl Fn(&I::Item) -> bool + Sync + Send,
+) -> (Option<I::Item>, usize)
+where
+    I: IntoParallelIterator,
+    I::Iter: IndexedParallelIterator,
+{
+    let iter = iter.into_par_iter();
+    let len = iter.len();
+    match iter.enumerate().find_first(|(_, item)| !cond(item)) {
+        Some((i, item)) => (Some(item), i),
+        None => (None, len),
+    }
+}
+
+/// Convenience trait to allow using [`par_discard_while`] as a method.
+/// This trait is implemented for every [`IndexedParallelIterator`].
+pub trait ParallelDiscardWhile: IndexedParallelIterator {
+    /// Find the first item that does not satisfy a condition,
+    /// checking the items on all threads of Rayon's thread pool.
+    ///
+    /// See [`par_discard_while`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::ParallelDiscardWhile;
+    /// # use rayon::prelude::*;
+    /// let text = "      padded";
+    /// let result = text.as_bytes().par_iter().discard_while(|b| b.is_ascii_whitespace());
+    /// assert_eq!(result, (Some(&b'p'), 6));
+    /// ```
+    fn discard_while(
+        self,
+        cond: impl Fn(&Self::Item) -> bool + Sync + Send,
+    ) -> (Option<Self::Item>, usize) {
+        par_discard_while(self, cond)
+    }
+}
+
+impl<I: IndexedParallelIterator> ParallelDiscardWhile for I {}
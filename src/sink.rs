@@ -0,0 +1,260 @@
+//! Collecting discarded elements into a sink.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// A collection that discarded elements can be pushed into, used by [`discard_while_into`].
+///
+/// Unlike [`Extend`], pushing can fail, which lets fixed-capacity collections
+/// hand back the element that did not fit.
+///
+/// This trait is implemented for:
+/// - `Vec`, with the `alloc` feature,
+/// - `heapless::Vec`, with the `heapless` feature,
+/// - mutable slices wrapped in [`SliceSink`],
+/// - any [`Extend`] implementation wrapped in [`Extending`],
+/// - mutable references to any of these.
+pub trait Sink<T> {
+    /// Pushes an element into the sink, returning it back if the sink is full.
+    fn push(&mut self, item: T) -> Result<(), T>;
+}
+
+impl<T, S: Sink<T> + ?Sized> Sink<T> for &mut S {
+    fn push(&mut self, item: T) -> Result<(), T> {
+        (**self).push(item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Sink<T> for alloc::vec::Vec<T> {
+    fn push(&mut self, item: T) -> Result<(), T> {
+        self.push(item);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, LenT, S> Sink<T> for heapless::vec::VecInner<T, LenT, S>
+where
+    LenT: heapless::LenType,
+    S: heapless::vec::VecStorage<T> + ?Sized,
+{
+    fn push(&mut self, item: T) -> Result<(), T> {
+        self.push(item)
+    }
+}
+
+/// A [`Sink`] for any [`Extend`] implementation, which is never full.
+///
+/// # Examples
+///
+/// ```
+/// # use discard_while::{discard_while_into, Extending};
+/// # use std::collections::HashSet;
+/// let mut seen = Extending(HashSet::new());
+/// let result = discard_while_into([3, 1, 3, 2, 0], &mut seen, |&n| n != 0);
+/// assert_eq!(result, Ok((Some(0), 4)));
+/// assert_eq!(seen.0, HashSet::from([1, 2, 3]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Extending<E>(pub E);
+
+impl<T, E: Extend<T>> Sink<T> for Extending<E> {
+    fn push(&mut self, item: T) -> Result<(), T> {
+        self.0.extend(Some(item));
+        Ok(())
+    }
+}
+
+/// A [`Sink`] that writes into a mutable slice, which is full once every slot has been written.
+///
+/// Each pushed element overwrites the next slot of the slice, starting from the front.
+///
+/// # Examples
+///
+/// ```
+/// # use discard_while::{discard_while_into, SinkFull, SliceSink};
+/// let mut buf = [0; 3];
+/// let mut sink = SliceSink::new(&mut buf);
+/// let mut range = 1..=10;
+/// let result = discard_while_into(&mut range, &mut sink, |&n| n != 8);
+/// assert_eq!(result, Err(SinkFull { item: 4, count: 3 }));
+/// assert_eq!(sink.filled(), [1, 2, 3]);
+/// assert_eq!(range, 5..=10);
+/// ```
+///
+/// Sinks are equal if they have been filled with the same elements, regardless of the unwritten slots.
+///
+/// ```
+/// # use discard_while::{Sink, SliceSink};
+/// let (mut a, mut b) = ([0; 4], [9; 2]);
+/// let (mut a, mut b) = (SliceSink::new(&mut a), SliceSink::new(&mut b));
+/// assert_eq!(a.push(1), Ok(()));
+/// assert_eq!(b.push(1), Ok(()));
+/// assert_eq!(a, b);
+/// ```
+pub struct SliceSink<'a, T> {
+    slice: &'a mut [T],
+    len: usize,
+}
+
+impl<'a, T> SliceSink<'a, T> {
+    /// Creates an empty sink writing into `slice`.
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self { slice, len: 0 }
+    }
+
+    /// Returns the number of elements pushed so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no elements have been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the part of the slice that has been written to.
+    pub fn filled(&self) -> &[T] {
+        &self.slice[..self.len]
+    }
+
+    /// Consumes the sink, returning the part of the slice that has been written to.
+    pub fn into_filled(self) -> &'a mut [T] {
+        &mut self.slice[..self.len]
+    }
+}
+
+impl<T> Sink<T> for SliceSink<'_, T> {
+    fn push(&mut self, item: T) -> Result<(), T> {
+        match self.slice.get_mut(self.len) {
+            Some(slot) => {
+                *slot = item;
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(item),
+        }
+    }
+}
+
+// Only the written part of the slice is compared, hashed and printed,
+// since the rest holds whatever the slice contained before.
+impl<T: PartialEq> PartialEq for SliceSink<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.filled() == other.filled()
+    }
+}
+
+impl<T: Eq> Eq for SliceSink<'_, T> {}
+
+impl<T: Hash> Hash for SliceSink<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.filled().hash(state);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SliceSink<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SliceSink")
+            .field("filled", &self.filled())
+            .field("capacity", &self.slice.len())
+            .finish()
+    }
+}
+
+/// The error returned by [`discard_while_into`] when the sink is full.
+///
+/// Holds the item that did not fit into the sink
+/// and the number of elements that were discarded into the sink before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SinkFull<T> {
+    /// The item that did not fit into the sink.
+    pub item: T,
+    /// The number of elements that were discarded into the sink.
+    pub count: usize,
+}
+
+impl<T> fmt::Display for SinkFull<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sink full after discarding {} elements", self.count)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> Error for SinkFull<T> {}
+
+/// The result of [`discard_while_into`].
+pub type DiscardedInto<T> = Result<(Option<T>, usize), SinkFull<T>>;
+
+/// Advance an iterator as long as a condition on the yielded items holds,
+/// pushing every discarded item into a [`Sink`].
+/// Returns the first item that no longer satisfies the condition, if any,
+/// and the number of items discarded.
+///
+/// This works like [`discard_while`](crate::discard_while), but keeps the discarded items.
+/// If the sink is full, it stops and returns the item that did not fit
+/// in a [`SinkFull`] error.
+/// The iterator can then be resumed after that item.
+///
+/// # Overflow Behavior
+///
+/// The method does no guarding against overflows, so if there are more than `usize::MAX`
+/// non-matching elements, it either produces the wrong result or panics.
+/// If overflow checks are enabled, a panic is guaranteed.
+///
+/// # Panics
+///
+/// This function might panic if the iterator has more than `usize::MAX` non-matching elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// # use discard_while::discard_while_into;
+/// let mut range = 1..=10;
+/// let mut discarded = Vec::new();
+/// let result = discard_while_into(&mut range, &mut discarded, |&n| n != 5);
+/// assert_eq!(result, Ok((Some(5), 4)));
+/// assert_eq!(discarded, [1, 2, 3, 4]);
+/// assert_eq!(range, 6..=10);
+/// # }
+/// ```
+///
+/// If the sink is full, the item that did not fit is returned in the error.
+///
+/// ```
+/// # #[cfg(feature = "heapless")] {
+/// # use discard_while::{discard_while_into, SinkFull};
+/// let mut range = 1..=10;
+/// let mut discarded = heapless::Vec::<_, 2>::new();
+/// let result = discard_while_into(&mut range, &mut discarded, |&n| n != 5);
+/// assert_eq!(result, Err(SinkFull { item: 3, count: 2 }));
+/// assert_eq!(discarded, [1, 2]);
+/// assert_eq!(range, 4..=10);
+/// # }
+/// ```
+pub fn discard_while_into<T>(
+    iter: impl IntoIterator<Item = T>,
+    mut sink: impl Sink<T>,
+    mut cond: impl FnMut(&T) -> bool,
+) -> DiscardedInto<T> {
+    let flow = iter.into_iter().try_fold(0, |i, next| {
+        if !cond(&next) {
+            return ControlFlow::Break(Ok((Some(next), i)));
+        }
+        match sink.push(next) {
+            Ok(()) => ControlFlow::Continue(i + 1),
+            Err(item) => ControlFlow::Break(Err(SinkFull { item, count: i })),
+        }
+    });
+    match flow {
+        ControlFlow::Continue(i) => Ok((None, i)),
+        ControlFlow::Break(result) => result,
+    }
+}
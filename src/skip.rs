@@ -0,0 +1,123 @@
+//! The lazy [`SkipWhileCounted`] adapter.
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::discard_while;
+
+/// An iterator that skips elements while a condition holds,
+/// and records how many elements it skipped.
+///
+/// This `struct` is created by the [`skip_while_counted`] method on [`DiscardWhile`].
+/// See its documentation for more.
+///
+/// [`skip_while_counted`]: crate::DiscardWhile::skip_while_counted
+/// [`DiscardWhile`]: crate::DiscardWhile
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SkipWhileCounted<I: Iterator, P> {
+    iter: I,
+    cond: P,
+    skipped: Option<usize>,
+    /// The first non-matching element, if it was found by [`next_back`](DoubleEndedIterator::next_back)
+    /// and has not been yielded yet.
+    front: Option<I::Item>,
+}
+
+impl<I: Iterator, P> SkipWhileCounted<I, P> {
+    pub(crate) fn new(iter: I, cond: P) -> Self {
+        SkipWhileCounted {
+            iter,
+            cond,
+            skipped: None,
+            front: None,
+        }
+    }
+
+    /// Returns the number of skipped elements,
+    /// or [`None`] if the adapter has not been advanced yet.
+    pub fn skipped(&self) -> Option<usize> {
+        self.skipped
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> SkipWhileCounted<I, P> {
+    /// Skips the leading matching elements, if that has not happened yet,
+    /// and returns the first non-matching element.
+    fn skip(&mut self) -> Option<I::Item> {
+        let (stopper, count) = discard_while(&mut self.iter, &mut self.cond);
+        self.skipped = Some(count);
+        stopper
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for SkipWhileCounted<I, P> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.skipped.is_none() {
+            return self.skip();
+        }
+        self.front.take().or_else(|| self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.skipped.is_none() {
+            // Any of the elements might still be skipped.
+            return (0, upper);
+        }
+        let front = usize::from(self.front.is_some());
+        (
+            lower.saturating_add(front),
+            upper.and_then(|upper| upper.checked_add(front)),
+        )
+    }
+}
+
+/// Calling [`next_back`](DoubleEndedIterator::next_back) before the leading elements
+/// were skipped skips them eagerly, so that no element that should be skipped
+/// is ever yielded from the back.
+impl<I, P> DoubleEndedIterator for SkipWhileCounted<I, P>
+where
+    I: DoubleEndedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<I::Item> {
+        if self.skipped.is_none() {
+            self.front = self.skip();
+        }
+        self.iter.next_back().or_else(|| self.front.take())
+    }
+}
+
+impl<I: FusedIterator, P: FnMut(&I::Item) -> bool> FusedIterator for SkipWhileCounted<I, P> {}
+
+impl<I, P> Clone for SkipWhileCounted<I, P>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        SkipWhileCounted {
+            iter: self.iter.clone(),
+            cond: self.cond.clone(),
+            skipped: self.skipped,
+            front: self.front.clone(),
+        }
+    }
+}
+
+impl<I, P> fmt::Debug for SkipWhileCounted<I, P>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SkipWhileCounted")
+            .field("iter", &self.iter)
+            .field("skipped", &self.skipped)
+            .field("front", &self.front)
+            .finish()
+    }
+}
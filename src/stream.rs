@@ -0,0 +1,191 @@
+//! Support for asynchronous [`Stream`]s, enabled by the `futures` feature.
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Convenience trait to allow using [`discard_while`](crate::discard_while) on streams.
+/// This trait is implemented for every [`Stream`] that is [`Unpin`].
+pub trait StreamDiscardWhile: Stream + Unpin {
+    /// Advance the stream as long as a condition on the yielded items holds.
+    /// Returns a future resolving to the first item that no longer satisfies the condition,
+    /// if any, and the number of items discarded.
+    ///
+    /// This is the asynchronous counterpart of [`DiscardWhile::discard_while`](crate::DiscardWhile::discard_while).
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so if there are more than `usize::MAX`
+    /// non-matching elements, it either produces the wrong result or panics.
+    /// If overflow checks are enabled, a panic is guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// The future might panic if the stream has more than `usize::MAX` non-matching elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::StreamDiscardWhile;
+    /// # use futures::{executor::block_on, stream, StreamExt};
+    /// let mut stream = stream::iter(1..=10);
+    /// let result = block_on(stream.discard_while(|&n| n != 5));
+    /// assert_eq!(result, (Some(5), 4));
+    /// assert_eq!(block_on(stream.next()), Some(6));
+    /// ```
+    fn discard_while<F>(&mut self, cond: F) -> DiscardWhileFuture<'_, Self, F>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        DiscardWhileFuture {
+            stream: self,
+            cond,
+            count: 0,
+        }
+    }
+
+    /// Advance the stream as long as an asynchronous condition on the yielded items holds.
+    /// Returns a future resolving to the first item that no longer satisfies the condition,
+    /// if any, and the number of items discarded.
+    ///
+    /// The future returned by the condition must not borrow the item.
+    /// While it is pending, the item is held by the returned future,
+    /// so dropping the returned future before it completes drops that item as well.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so if there are more than `usize::MAX`
+    /// non-matching elements, it either produces the wrong result or panics.
+    /// If overflow checks are enabled, a panic is guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// The future might panic if the stream has more than `usize::MAX` non-matching elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use discard_while::StreamDiscardWhile;
+    /// # use futures::{executor::block_on, stream};
+    /// let mut stream = stream::iter(1..=10);
+    /// let result = block_on(stream.discard_while_async(|&n| async move { n != 5 }));
+    /// assert_eq!(result, (Some(5), 4));
+    /// ```
+    fn discard_while_async<F, Fut>(&mut self, cond: F) -> DiscardWhileAsyncFuture<'_, Self, F, Fut>
+    where
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        DiscardWhileAsyncFuture {
+            stream: self,
+            cond,
+            pending: None,
+            item: None,
+            count: 0,
+        }
+    }
+}
+
+impl<S: Stream + Unpin + ?Sized> StreamDiscardWhile for S {}
+
+/// Future for the [`discard_while`](StreamDiscardWhile::discard_while) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DiscardWhileFuture<'a, S: ?Sized, F> {
+    stream: &'a mut S,
+    cond: F,
+    count: usize,
+}
+
+impl<S: ?Sized, F> Unpin for DiscardWhileFuture<'_, S, F> {}
+
+impl<S: fmt::Debug + ?Sized, F> fmt::Debug for DiscardWhileFuture<'_, S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiscardWhileFuture")
+            .field("stream", &self.stream)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<S, F> Future for DiscardWhileFuture<'_, S, F>
+where
+    S: Stream + Unpin + ?Sized,
+    F: FnMut(&S::Item) -> bool,
+{
+    type Output = (Option<S::Item>, usize);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            match ready!(Pin::new(&mut *this.stream).poll_next(cx)) {
+                Some(item) if (this.cond)(&item) => this.count += 1,
+                item => return Poll::Ready((item, this.count)),
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// Future for the [`discard_while_async`](StreamDiscardWhile::discard_while_async) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct DiscardWhileAsyncFuture<'a, S, F, Fut>
+    where
+        S: Stream,
+        S: ?Sized,
+    {
+        stream: &'a mut S,
+        cond: F,
+        #[pin]
+        pending: Option<Fut>,
+        item: Option<S::Item>,
+        count: usize,
+    }
+}
+
+impl<S, F, Fut> fmt::Debug for DiscardWhileAsyncFuture<'_, S, F, Fut>
+where
+    S: Stream + fmt::Debug + ?Sized,
+    S::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiscardWhileAsyncFuture")
+            .field("stream", &self.stream)
+            .field("item", &self.item)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<S, F, Fut> Future for DiscardWhileAsyncFuture<'_, S, F, Fut>
+where
+    S: Stream + Unpin + ?Sized,
+    F: FnMut(&S::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    type Output = (Option<S::Item>, usize);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            if let Some(pending) = this.pending.as_mut().as_pin_mut() {
+                let keep_discarding = ready!(pending.poll(cx));
+                this.pending.set(None);
+                let item = this.item.take();
+                if !keep_discarding {
+                    return Poll::Ready((item, *this.count));
+                }
+                *this.count += 1;
+            }
+            match ready!(Pin::new(&mut **this.stream).poll_next(cx)) {
+                Some(item) => {
+                    this.pending.set(Some((this.cond)(&item)));
+                    *this.item = Some(item);
+                }
+                None => return Poll::Ready((None, *this.count)),
+            }
+        }
+    }
+}
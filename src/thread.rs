@@ -0,0 +1,206 @@
+//! Multithreaded discarding on slices using scoped threads, enabled by the `std` feature.
+
+use core::num::NonZeroUsize;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::panic;
+use std::sync::OnceLock;
+use std::thread;
+use std::vec::Vec;
+
+use crate::split_discard_while;
+
+/// Slices shorter than this are scanned on the current thread.
+const MIN_PARALLEL_LEN: usize = 1 << 14;
+/// The number of elements each thread checks between looking for cancellation.
+const BLOCK_LEN: usize = 1 << 10;
+
+/// Find the first element of a slice that does not satisfy a condition,
+/// splitting the slice into chunks scanned on separate scoped threads.
+/// Returns the first element that does not satisfy the condition, if any,
+/// and the number of elements before it.
+///
+/// The result is the same as with [`discard_while`](crate::discard_while) on the slice,
+/// even though the condition may be called on elements after the returned one.
+/// Once a thread finds a non-matching element, the threads scanning later chunks stop early.
+///
+/// The slice is split into one chunk per available core, as reported by
+/// [`available_parallelism`](thread::available_parallelism), which is only queried once.
+/// The first chunk is scanned on the current thread, and a thread is spawned for each other chunk.
+/// Short slices are scanned on the current thread only.
+///
+/// # Panics
+///
+/// If the condition panics on any thread, the panic is propagated.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use discard_while::par_discard_while_slice;
+/// let mut data = vec![0u8; 1 << 20];
+/// data[700_000] = 1;
+/// let result = par_discard_while_slice(&data, |&b| b == 0);
+/// assert_eq!(result, (Some(&1), 700_000));
+/// ```
+///
+/// If all elements fulfill the condition, [`None`] is returned as the first return value.
+///
+/// ```
+/// # use discard_while::par_discard_while_slice;
+/// let data = vec![0u8; 1 << 20];
+/// let result = par_discard_while_slice(&data, |&b| b == 0);
+/// assert_eq!(result, (None, 1 << 20));
+/// ```
+pub fn par_discard_while_slice<T: Sync>(
+    slice: &[T],
+    cond: impl Fn(&T) -> bool + Sync,
+) -> (Option<&T>, usize) {
+    let threads = if slice.len() < MIN_PARALLEL_LEN {
+        1
+    } else {
+        available_parallelism()
+    };
+    scan(slice, cond, threads)
+}
+
+/// Scans the slice like [`par_discard_while_slice`], split into at most `threads` chunks.
+fn scan<T: Sync>(
+    slice: &[T],
+    cond: impl Fn(&T) -> bool + Sync,
+    threads: usize,
+) -> (Option<&T>, usize) {
+    if threads == 1 || slice.is_empty() {
+        let (discarded, stopper, _) = split_discard_while(slice, cond);
+        return (stopper, discarded.len());
+    }
+    let chunk_len = slice.len().div_ceil(threads);
+    // The index of the first chunk known to contain a non-matching element.
+    let found = AtomicUsize::new(usize::MAX);
+    let positions: Vec<Option<usize>> = thread::scope(|scope| {
+        let mut chunks = slice.chunks(chunk_len).enumerate();
+        let (_, first) = chunks.next().unwrap();
+        let handles: Vec<_> = chunks
+            .map(|(index, chunk)| {
+                let (cond, found) = (&cond, &found);
+                scope.spawn(move || scan_chunk(index, chunk, cond, found))
+            })
+            .collect();
+        let first = scan_chunk(0, first, &cond, &found);
+        let rest = handles.into_iter().map(|handle| {
+            handle
+                .join()
+                .unwrap_or_else(|err| panic::resume_unwind(err))
+        });
+        [first].into_iter().chain(rest).collect()
+    });
+    // A chunk only stops early if an earlier chunk contains a non-matching element,
+    // so all chunks before the first one with a position were scanned completely.
+    match positions
+        .iter()
+        .enumerate()
+        .find_map(|(index, position)| position.map(|i| index * chunk_len + i))
+    {
+        Some(count) => (Some(&slice[count]), count),
+        None => (None, slice.len()),
+    }
+}
+
+/// Returns the number of available cores, querying it on the first call only.
+fn available_parallelism() -> usize {
+    static THREADS: OnceLock<usize> = OnceLock::new();
+    *THREADS.get_or_init(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+/// Returns the position of the first non-matching element in the chunk with the given index,
+/// or [`None`] if there is none or an earlier chunk already contains one.
+fn scan_chunk<T>(
+    index: usize,
+    chunk: &[T],
+    cond: impl Fn(&T) -> bool,
+    found: &AtomicUsize,
+) -> Option<usize> {
+    for (block_index, block) in chunk.chunks(BLOCK_LEN).enumerate() {
+        if found.load(Ordering::Relaxed) < index {
+            return None;
+        }
+        if let Some(i) = block.iter().position(|item| !cond(item)) {
+            found.fetch_min(index, Ordering::Relaxed);
+            return Some(block_index * BLOCK_LEN + i);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scan, BLOCK_LEN};
+    use std::vec;
+
+    const THREADS: usize = 4;
+    const CHUNK_LEN: usize = 3 * BLOCK_LEN;
+    const LEN: usize = THREADS * CHUNK_LEN;
+
+    /// Scans a slice of zeros with ones at the given positions.
+    fn scan_with_stoppers(len: usize, stoppers: &[usize]) -> (Option<u8>, usize) {
+        let mut data = vec![0u8; len];
+        for &i in stoppers {
+            data[i] = 1;
+        }
+        let (stopper, count) = scan(&data, |&b| b == 0, THREADS);
+        (stopper.copied(), count)
+    }
+
+    #[test]
+    fn stopper_at_boundaries() {
+        let positions = [
+            0,
+            1,
+            BLOCK_LEN - 1,
+            BLOCK_LEN,
+            CHUNK_LEN - 1,
+            CHUNK_LEN,
+            CHUNK_LEN + BLOCK_LEN - 1,
+            CHUNK_LEN + BLOCK_LEN,
+            2 * CHUNK_LEN,
+            3 * CHUNK_LEN + 2 * BLOCK_LEN,
+            LEN - 1,
+        ];
+        for i in positions {
+            assert_eq!(
+                scan_with_stoppers(LEN, &[i]),
+                (Some(1), i),
+                "stopper at {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn second_stopper_in_later_chunk() {
+        let pairs = [
+            (0, CHUNK_LEN),
+            (CHUNK_LEN - 1, CHUNK_LEN),
+            (BLOCK_LEN, 3 * CHUNK_LEN),
+            (CHUNK_LEN + BLOCK_LEN, 2 * CHUNK_LEN + BLOCK_LEN),
+            (2 * CHUNK_LEN - 1, LEN - 1),
+        ];
+        for (first, second) in pairs {
+            let result = scan_with_stoppers(LEN, &[first, second]);
+            assert_eq!(result, (Some(1), first), "stoppers at {first} and {second}");
+        }
+    }
+
+    #[test]
+    fn no_stopper() {
+        assert_eq!(scan_with_stoppers(LEN, &[]), (None, LEN));
+        assert_eq!(scan_with_stoppers(0, &[]), (None, 0));
+    }
+
+    #[test]
+    fn uneven_chunks() {
+        // Fewer elements than threads, and a last chunk shorter than the others.
+        assert_eq!(scan_with_stoppers(3, &[2]), (Some(1), 2));
+        assert_eq!(scan_with_stoppers(LEN + 1, &[LEN]), (Some(1), LEN));
+        assert_eq!(scan_with_stoppers(LEN + 1, &[]), (None, LEN + 1));
+    }
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "tokio")]
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use discard_while::AsyncBufReadDiscardWhile;
+use futures::FutureExt;
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+
+/// A reader that yields its buffer and then stays pending forever.
+#[derive(Debug)]
+struct Stalling<'a> {
+    buf: &'a [u8],
+}
+
+impl AsyncRead for Stalling<'_> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        out: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.buf.is_empty() {
+            return Poll::Pending;
+        }
+        let amount = this.buf.len().min(out.remaining());
+        out.put_slice(&this.buf[..amount]);
+        this.buf = &this.buf[amount..];
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncBufRead for Stalling<'_> {
+    fn poll_fill_buf(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if this.buf.is_empty() {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(this.buf))
+        }
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amount: usize) {
+        self.buf = &self.buf[amount..];
+    }
+}
+
+#[test]
+fn count_survives_cancellation() {
+    let mut reader = Stalling { buf: b"     " };
+    let mut future = reader.discard_while(|&b| b == b' ');
+    assert!((&mut future).now_or_never().is_none());
+    assert_eq!(future.count(), 5);
+    assert_eq!(
+        format!("{future:?}"),
+        r#"DiscardBytesWhile { reader: Stalling { buf: [] }, count: 5, consume_stopper: true }"#
+    );
+    drop(future);
+    assert!(reader.buf.is_empty());
+}
@@ -0,0 +1,67 @@
+use discard_while::{discard_bytes_while, discard_while, ByteSet};
+
+/// The number of bytes in a word, as scanned by `discard_bytes_while`.
+const WORD: usize = size_of::<usize>();
+/// The number of words `discard_bytes_while` checks before branching.
+const UNROLL: usize = 4;
+/// Covers several unrolled blocks, a partial block, and a tail shorter than a word.
+const MAX_LEN: usize = 4 * WORD * UNROLL + WORD;
+
+/// Bytes that are likely to trip up the word-at-a-time comparisons.
+const EDGES: [u8; 10] = [0x00, 0x01, b'0', b'9', 0x7F, 0x80, 0x81, 0xC0, 0xFE, 0xFF];
+
+/// Sets of 0 to 8 members, made of scattered bytes, of one range,
+/// and of two ranges, starting at several edges.
+fn sets() -> Vec<ByteSet> {
+    let mut sets = Vec::new();
+    for size in 0..=8 {
+        for (i, &start) in EDGES.iter().enumerate() {
+            let scattered: Vec<u8> = EDGES.iter().cycle().skip(i).take(size).copied().collect();
+            sets.push(ByteSet::new(&scattered));
+            if size == 0 {
+                continue;
+            }
+            let size = size as u8;
+            let start = start.min(u8::MAX - size);
+            sets.push(ByteSet::range(start, start + size - 1));
+            let half = size / 2;
+            if half > 0 {
+                let first = ByteSet::range(start, start + half - 1);
+                sets.push(first.union(ByteSet::range(start + half + 1, start + size)));
+            }
+        }
+    }
+    sets.push(ByteSet::ASCII_WHITESPACE);
+    sets.push(ByteSet::ASCII_DIGIT);
+    sets.push(ByteSet::range(0x20, 0xFF));
+    sets.push(ByteSet::range(0x00, 0xFF));
+    sets
+}
+
+#[test]
+fn matches_discard_while() {
+    for set in sets() {
+        let members: Vec<u8> = (0..=u8::MAX).filter(|&b| set.contains(b)).collect();
+        let outsiders: Vec<u8> = (0..=u8::MAX).filter(|&b| !set.contains(b)).collect();
+        for len in 0..=MAX_LEN {
+            let filler: Vec<u8> = if members.is_empty() {
+                outsiders.iter().copied().cycle().take(len).collect()
+            } else {
+                members.iter().copied().cycle().take(len).collect()
+            };
+            // A stopper at every position, and none at all.
+            for stop in 0..=len {
+                let mut bytes = filler.clone();
+                if stop < len && !outsiders.is_empty() {
+                    bytes[stop] = outsiders[(len + stop) % outsiders.len()];
+                }
+                let (stopper, count) = discard_while(&bytes, |&&b| set.contains(b));
+                assert_eq!(
+                    discard_bytes_while(&bytes, set),
+                    (stopper.copied(), count),
+                    "set {set:?}, bytes {bytes:?}",
+                );
+            }
+        }
+    }
+}
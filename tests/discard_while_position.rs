@@ -0,0 +1,64 @@
+use discard_while::{
+    discard_while_indexed, discard_while_position, discard_while_position_monotone,
+};
+
+#[test]
+fn unknown_length_all_matching() {
+    let iter = (0..10).filter(|_| true);
+    assert_eq!(discard_while_position(iter, |_| true), (None, 10));
+}
+
+#[test]
+fn unknown_length_stops_at_position() {
+    let mut iter = (0..10).filter(|n| n % 2 == 0);
+    assert_eq!(discard_while_position(&mut iter, |i| i < 3), (Some(6), 3));
+    assert_eq!(iter.collect::<Vec<_>>(), [8]);
+}
+
+#[test]
+fn unknown_length_condition_past_end() {
+    let iter = (0..10).filter(|_| true);
+    assert_eq!(discard_while_position(iter, |i| i < 20), (None, 10));
+}
+
+#[test]
+fn monotone_full_range_all_matching() {
+    let mut calls = 0;
+    let result = discard_while_position_monotone(0..usize::MAX, |_| {
+        calls += 1;
+        true
+    });
+    assert_eq!(result, (None, usize::MAX));
+    assert!(calls < 300);
+}
+
+#[test]
+fn monotone_unknown_length_all_matching() {
+    let iter = (0..10).filter(|_| true);
+    assert_eq!(discard_while_position_monotone(iter, |_| true), (None, 10));
+}
+
+#[test]
+fn not_monotone() {
+    for offset in 0..8 {
+        let cond = |i: usize| !(offset + i).is_multiple_of(8);
+        let mut range = 0..100;
+        let expected = discard_while_indexed(0..100, |i, _| cond(i));
+        assert_eq!(discard_while_position(&mut range, cond), expected);
+        assert_eq!(range.start, expected.1 + 1);
+
+        let filtered = (0..100).filter(|_| true);
+        assert_eq!(discard_while_position(filtered, cond), expected);
+    }
+}
+
+#[test]
+fn not_monotone_past_lower_bound() {
+    // The first few items are known to exist, the rest are only found by stepping.
+    let mut iter = (0..5).chain((5..20).filter(|_| true));
+    let result = discard_while_position(&mut iter, |i| i != 2 && i != 7);
+    assert_eq!(result, (Some(2), 2));
+    let result = discard_while_position(&mut iter, |i| i % 7 != 6);
+    assert_eq!(result, (Some(9), 6));
+    assert_eq!(iter.next(), Some(10));
+}
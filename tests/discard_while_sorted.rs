@@ -0,0 +1,24 @@
+use discard_while::discard_while_sorted;
+
+#[test]
+fn full_range_all_matching() {
+    let mut range = 0..usize::MAX;
+    assert_eq!(
+        discard_while_sorted(&mut range, |_| true),
+        (None, usize::MAX)
+    );
+    assert_eq!(range.next(), None);
+}
+
+#[test]
+fn stopper_at_usize_max() {
+    let mut range = 0..=usize::MAX;
+    let result = discard_while_sorted(&mut range, |&n| n < usize::MAX);
+    assert_eq!(result, (Some(usize::MAX), usize::MAX));
+}
+
+#[test]
+#[should_panic = "more than usize::MAX items satisfy the condition"]
+fn more_than_usize_max_matching() {
+    discard_while_sorted(&mut (0..=usize::MAX), |_| true);
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "futures")]
+
+use discard_while::StreamDiscardWhile;
+use futures::{stream, FutureExt, StreamExt};
+
+#[test]
+fn debug_omits_condition() {
+    let mut stream = stream::iter(1..=3);
+    let future = stream.discard_while(|&n| n < 2);
+    assert_eq!(
+        format!("{future:?}"),
+        "DiscardWhileFuture { stream: Iter { iter: 1..=3 }, count: 0 }"
+    );
+}
+
+#[test]
+fn debug_omits_async_condition() {
+    let mut stream = stream::iter(1..=3).chain(stream::pending());
+    let mut future = Box::pin(stream.discard_while_async(|&n| async move { n < 5 }));
+    assert!(future.as_mut().now_or_never().is_none());
+    let debug = format!("{future:?}");
+    assert!(debug.starts_with("DiscardWhileAsyncFuture { stream: "));
+    assert!(debug.ends_with(", item: None, count: 3 }"));
+}